@@ -0,0 +1,315 @@
+//! A derive macro to validate a submitted form straight into a struct.
+//!
+//! `#[derive(FormCheck)]` makes a `form_checker::Checker` for each field of a
+//! struct, and generates:
+//!
+//! * `fn form_validator() -> Validator`, the validator with all the checkers.
+//! * `fn from_form(params) -> Result<Self, ValidationResult>`, which validates
+//!   the params, any `Source` like a `HashMap` or a multipart `Form`, and
+//!   fills the struct, or hands back the invalid result.
+//! * `fn from_result(result) -> Result<Self, ValidationResult>`, which fills
+//!   the struct from a result you got yourself, eg. with a custom renderer.
+//!
+//! Each field is checked according to its `#[form(...)]` attribute:
+//!
+//! * `name = "..."`, the field name in the form, default to the struct field name.
+//! * `title = "..."`, the field title, default to the field name.
+//! * `field_type = ...`, the `FieldType`, which can be left out for `String`
+//!   (`Str`), `i64` (`I64`), `f64` (`F64::new()`), `DecimalValue`
+//!   (`Decimal::new()`), `bool` (`Bool::new()`), `NaiveDate` (`Date::new()`)
+//!   and `NaiveTime` (`Time::new()`).
+//! * `filter = ...`, a `Filter` to clean the value with, can be repeated.
+//! * `rule = ...`, a `Rule` to meet, can be repeated.
+//! * `option = ...`, a `CheckerOption` to set, can be repeated.
+//!
+//! An `Option<T>` field is optional and a `Vec<T>` field is multiple.
+//!
+//! An option which may leave a field missing, like `CheckerOption::Optional`
+//! or `CheckerOption::RequiredIf`, is only allowed on an `Option<T>` field:
+//!
+//! ```compile_fail
+//! # #[macro_use] extern crate form_checker_derive;
+//! # extern crate form_checker;
+//! use form_checker::CheckerOption;
+//!
+//! #[derive(FormCheck)]
+//! struct Signup {
+//!     #[form(option = CheckerOption::RequiredWith("name".to_string()))]
+//!     nickname: String,
+//! }
+//! # fn main() {}
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! # #[macro_use] extern crate form_checker_derive;
+//! # extern crate form_checker;
+//! use form_checker::{Rule, Email};
+//!
+//! #[derive(FormCheck)]
+//! struct Signup {
+//!     #[form(title = "姓名", rule = Rule::Max(5), rule = Rule::Min(2))]
+//!     name: String,
+//!     #[form(title = "年龄", rule = Rule::Min(18))]
+//!     age: i64,
+//!     #[form(title = "邮箱", field_type = Email)]
+//!     email: Option<String>,
+//!     #[form(name = "tag", title = "标签")]
+//!     tags: Vec<String>,
+//! }
+//!
+//! # fn main() {
+//! let mut params = std::collections::HashMap::new();
+//! params.insert("name".to_string(), vec!["bob".to_string()]);
+//! params.insert("age".to_string(), vec!["20".to_string()]);
+//! params.insert("tag".to_string(), vec!["red".to_string(), "blue".to_string()]);
+//!
+//! let signup = Signup::from_form(&params).ok().unwrap();
+//! assert_eq!(signup.name, "bob");
+//! assert_eq!(signup.age, 20);
+//! assert_eq!(signup.email, None);
+//! assert_eq!(signup.tags, vec!["red", "blue"]);
+//!
+//! params.insert("age".to_string(), vec!["16".to_string()]);
+//! let result = Signup::from_form(&params).err().unwrap();
+//! assert_eq!(result.get_error("age"), "年龄不能小于18");
+//! # }
+//! ```
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Expr, Fields, GenericArgument, LitStr,
+          PathArguments, Type};
+
+#[proc_macro_derive(FormCheck, attributes(form))]
+pub fn derive_form_check(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(|e| e.to_compile_error()).into()
+}
+
+/// What a `#[form(...)]` attribute says about a field.
+#[derive(Default)]
+struct FieldAttrs {
+    name: Option<LitStr>,
+    title: Option<LitStr>,
+    field_type: Option<Expr>,
+    filters: Vec<Expr>,
+    rules: Vec<Expr>,
+    options: Vec<Expr>,
+}
+
+/// How the values of a field are held by the struct.
+enum Shape {
+    Single,
+    Optional,
+    Multiple,
+    OptionalMultiple,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "FormCheck can't be derived for a generic struct"));
+    }
+    let fields = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => &fields.named,
+            _ => return Err(Error::new_spanned(ident, "FormCheck can only be derived for a struct with named fields")),
+        },
+        _ => return Err(Error::new_spanned(ident, "FormCheck can only be derived for a struct")),
+    };
+
+    let mut checkers = Vec::new();
+    let mut extracts = Vec::new();
+    for field in fields {
+        let field_ident = field.ident.as_ref().expect("named field");
+        let attrs = parse_attrs(&field.attrs)?;
+
+        let name = match attrs.name {
+            Some(name) => name.value(),
+            None => field_ident.to_string().trim_start_matches("r#").to_string(),
+        };
+        let title = attrs.title.map(|t| t.value()).unwrap_or_else(|| name.clone());
+        let (shape, inner) = shape_of(&field.ty);
+        let field_type = match attrs.field_type {
+            Some(field_type) => quote!(#field_type),
+            None => infer_field_type(inner)?,
+        };
+
+        let shape_options = match shape {
+            Shape::Single => quote!(),
+            Shape::Optional => quote!(.set(::form_checker::CheckerOption::Optional(true))),
+            Shape::Multiple => quote!(.set(::form_checker::CheckerOption::Multiple(true))),
+            Shape::OptionalMultiple => quote!(
+                .set(::form_checker::CheckerOption::Optional(true))
+                .set(::form_checker::CheckerOption::Multiple(true))
+            ),
+        };
+        if let Shape::Single | Shape::Multiple = shape {
+            if let Some(option) = attrs.options.iter().find(|option| may_leave_missing(option)) {
+                return Err(Error::new_spanned(option, "this option may leave the field missing, make it an `Option`"));
+            }
+        }
+        let filters = &attrs.filters;
+        let rules = &attrs.rules;
+        let options = &attrs.options;
+        checkers.push(quote! {
+            ::form_checker::Checker::new(#name, #title, #field_type)
+                #shape_options
+                #(.set(#options))*
+                #(.filter(#filters))*
+                #(.meet(#rules))*
+        });
+
+        let mismatch = format!("field `{}` has a value of an unexpected type", name);
+        let convert = quote! {
+            |value| <#inner as ::form_checker::FromFieldValue>::from_field_value(value).expect(#mismatch)
+        };
+        let extract = match shape {
+            Shape::Single => quote!((#convert)(result.get_required(#name))),
+            Shape::Optional => quote!(result.get_optional(#name).map(#convert)),
+            Shape::Multiple => quote!(result.get_required_multiple(#name).into_iter().map(#convert).collect()),
+            Shape::OptionalMultiple => quote!(
+                result.get_optional_multiple(#name).map(|values| values.into_iter().map(#convert).collect())
+            ),
+        };
+        extracts.push(quote!(#field_ident: #extract));
+    }
+
+    Ok(quote! {
+        impl #ident {
+            /// Make a validator with a checker for each field.
+            pub fn form_validator() -> ::form_checker::Validator {
+                let mut validator = ::form_checker::Validator::new();
+                #(validator.check(#checkers);)*
+                validator
+            }
+
+            /// Validate the params and fill a struct with the valid values.
+            pub fn from_form(params: &dyn ::form_checker::Source)
+                             -> ::std::result::Result<Self, ::form_checker::ValidationResult> {
+                static VALIDATOR: ::std::sync::OnceLock<::form_checker::Validator> = ::std::sync::OnceLock::new();
+                Self::from_result(VALIDATOR.get_or_init(Self::form_validator).evaluate(&params))
+            }
+
+            /// Fill a struct from a validation result, or hand the result back if invalid.
+            pub fn from_result(result: ::form_checker::ValidationResult)
+                               -> ::std::result::Result<Self, ::form_checker::ValidationResult> {
+                if !result.is_valid() {
+                    return ::std::result::Result::Err(result);
+                }
+                ::std::result::Result::Ok(#ident {
+                    #(#extracts,)*
+                })
+            }
+        }
+    })
+}
+
+fn parse_attrs(attrs: &[Attribute]) -> syn::Result<FieldAttrs> {
+    let mut out = FieldAttrs::default();
+    for attr in attrs {
+        if !attr.path().is_ident("form") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                out.name = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("title") {
+                out.title = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("field_type") {
+                out.field_type = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("filter") {
+                out.filters.push(meta.value()?.parse()?);
+            } else if meta.path.is_ident("rule") {
+                out.rules.push(meta.value()?.parse()?);
+            } else if meta.path.is_ident("option") {
+                out.options.push(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error("expected `name`, `title`, `field_type`, `filter`, `rule` or `option`"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(out)
+}
+
+/// True if a `CheckerOption` may leave a field missing while it is valid.
+fn may_leave_missing(option: &Expr) -> bool {
+    let func = match *option {
+        Expr::Call(ref call) => &call.func,
+        _ => return false,
+    };
+    match **func {
+        Expr::Path(ref path) => path.path.segments.last().is_some_and(|segment| {
+            ["Optional", "RequiredIf", "RequiredUnless", "RequiredWith", "RequiredWithout", "ExcludedIf"]
+                .iter().any(|name| segment.ident == name)
+        }),
+        _ => false,
+    }
+}
+
+/// Tell the shape of a field type, and the type of a single value in it.
+fn shape_of(ty: &Type) -> (Shape, &Type) {
+    if let Some(inner) = generic_arg(ty, "Option") {
+        if let Some(item) = generic_arg(inner, "Vec") {
+            return (Shape::OptionalMultiple, item);
+        }
+        return (Shape::Optional, inner);
+    }
+    if let Some(item) = generic_arg(ty, "Vec") {
+        return (Shape::Multiple, item);
+    }
+    (Shape::Single, ty)
+}
+
+/// The only type argument of `ty`, if `ty` is a `wrapper<T>`.
+fn generic_arg<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let segment = match *ty {
+        Type::Path(ref path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != wrapper {
+        return None;
+    }
+    match segment.arguments {
+        PathArguments::AngleBracketed(ref args) if args.args.len() == 1 => match args.args[0] {
+            GenericArgument::Type(ref inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn infer_field_type(ty: &Type) -> syn::Result<TokenStream2> {
+    if let Type::Path(ref path) = *ty {
+        if let Some(segment) = path.path.segments.last() {
+            if segment.ident == "String" {
+                return Ok(quote!(::form_checker::Str));
+            }
+            if segment.ident == "i64" {
+                return Ok(quote!(::form_checker::I64));
+            }
+            if segment.ident == "f64" {
+                return Ok(quote!(::form_checker::F64::new()));
+            }
+            if segment.ident == "DecimalValue" {
+                return Ok(quote!(::form_checker::Decimal::new()));
+            }
+            if segment.ident == "bool" {
+                return Ok(quote!(::form_checker::Bool::new()));
+            }
+            if segment.ident == "NaiveDate" {
+                return Ok(quote!(::form_checker::Date::new()));
+            }
+            if segment.ident == "NaiveTime" {
+                return Ok(quote!(::form_checker::Time::new()));
+            }
+        }
+    }
+    Err(Error::new_spanned(ty, "can't infer a field type, add `#[form(field_type = ...)]`"))
+}
@@ -0,0 +1,102 @@
+#[macro_use]
+extern crate form_checker_derive;
+extern crate form_checker;
+
+use std::collections::HashMap;
+use form_checker::{Rule, CheckerOption, ChinaMobile, FieldValue, Form, Str, Trim};
+use form_checker::chrono::NaiveDate;
+
+#[derive(FormCheck)]
+struct Profile {
+    #[form(title = "用户名", filter = Trim, rule = Rule::Max(5), rule = Rule::Min(2))]
+    username: String,
+    #[form(title = "年龄", rule = Rule::Max(100), rule = Rule::Min(18))]
+    age: i64,
+    #[form(title = "手机", field_type = ChinaMobile)]
+    mobile: Option<String>,
+    #[form(name = "tag", title = "标签", option = CheckerOption::CollectAll(true), rule = Rule::Min(2))]
+    tags: Vec<String>,
+    #[form(title = "备注", field_type = Str)]
+    notes: Option<Vec<FieldValue>>,
+    #[form(title = "身高", rule = Rule::MaxF64(2.5))]
+    height: Option<f64>,
+    #[form(title = "生日")]
+    birthday: Option<NaiveDate>,
+}
+
+fn params(pairs: &[(&str, &[&str])]) -> HashMap<String, Vec<String>> {
+    pairs.iter()
+        .map(|&(k, vs)| (k.to_string(), vs.iter().map(|v| v.to_string()).collect()))
+        .collect()
+}
+
+#[test]
+fn from_form_valid() {
+    let profile = Profile::from_form(&params(&[
+        ("username", &[" bob "]),
+        ("age", &["20"]),
+        ("tag", &["red", "blue"]),
+        ("notes", &["a", "b"]),
+        ("height", &["1.75"]),
+        ("birthday", &["2000-02-29"]),
+    ])).ok().unwrap();
+
+    assert_eq!(profile.username, "bob");
+    assert_eq!(profile.age, 20);
+    assert_eq!(profile.mobile, None);
+    assert_eq!(profile.tags, vec!["red".to_string(), "blue".to_string()]);
+    assert_eq!(profile.notes, Some(vec![FieldValue::Str("a".to_string()), FieldValue::Str("b".to_string())]));
+    assert_eq!(profile.height, Some(1.75));
+    assert_eq!(profile.birthday, NaiveDate::from_ymd_opt(2000, 2, 29));
+}
+
+#[test]
+fn from_form_invalid() {
+    let result = Profile::from_form(&params(&[
+        ("username", &["b"]),
+        ("mobile", &["23444"]),
+        ("tag", &["r", "b"]),
+        ("height", &["3"]),
+        ("birthday", &["2001-02-29"]),
+    ])).err().unwrap();
+
+    assert!(!result.is_valid());
+    assert_eq!(result.get_error("username"), "用户名长度不能小于2");
+    assert_eq!(result.get_error("age"), "年龄不能为空");
+    assert_eq!(result.get_error("mobile"), "手机格式不正确");
+    assert_eq!(result.get_field_errors("tag").len(), 2);
+    assert_eq!(result.get_error("height"), "身高不能大于2.5");
+    assert_eq!(result.get_error("birthday"), "生日格式不正确");
+}
+
+#[test]
+fn form_validator() {
+    let validator = Profile::form_validator();
+    assert_eq!(validator.checkers.iter().map(|c| c.get_name()).collect::<Vec<_>>(),
+               vec!["username", "age", "mobile", "tag", "notes", "height", "birthday"]);
+}
+
+#[derive(FormCheck)]
+struct Company {
+    #[form(title = "账户类型")]
+    account_type: String,
+    #[form(title = "公司名称", option = CheckerOption::RequiredIf("account_type".to_string(), "business".to_string()))]
+    company: Option<String>,
+}
+
+#[test]
+fn from_form_source() {
+    let mut form = Form::default();
+    form.fields.insert("account_type".to_string(), vec!["personal".to_string()]);
+    let company = Company::from_form(&form).ok().unwrap();
+    assert_eq!(company.account_type, "personal");
+    assert_eq!(company.company, None);
+
+    form.fields.insert("account_type".to_string(), vec!["business".to_string()]);
+    let result = Company::from_form(&form).err().unwrap();
+    assert_eq!(result.get_error("company"), "公司名称不能为空");
+
+    let params: &dyn form_checker::Source = &params(&[("account_type", &["business"]), ("company", &["acme"])]);
+    let company = Company::from_form(params).ok().unwrap();
+    assert_eq!(company.company, Some("acme".to_string()));
+}
@@ -0,0 +1,327 @@
+//! Dates and times, the `Date`, `Time` and `DateTime` field types, and the
+//! rules about them.
+
+use std::cmp::Ordering;
+
+use chrono::{self, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+
+use {FieldType, FieldValue, Message, MessageKind};
+
+/// A field type to represent a date, eg. a birthday.
+///
+/// By default, a date is in ISO 8601, like `2017-03-09`, which is what an
+/// HTML date input submits. Call `format` to take a strftime-style pattern
+/// instead, refer to `chrono::format::strftime`.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate form_checker;
+/// # extern crate chrono;
+/// # use form_checker::{Validator, Checker, Date};
+/// # use chrono::NaiveDate;
+/// # fn main() {
+/// let mut validator = Validator::new();
+/// validator
+///     .check(Checker::new("birthday", "生日", Date::new()))
+///     .check(Checker::new("expiry", "有效期", Date::new().format("%d/%m/%Y")));
+///
+/// let mut params = std::collections::HashMap::new();
+/// params.insert("birthday".to_string(), vec!["2017-03-09".to_string()]);
+/// params.insert("expiry".to_string(), vec!["31/12/2030".to_string()]);
+/// let result = validator.evaluate(&params);
+/// assert_eq!(result.try_get_date("birthday"), Ok(NaiveDate::from_ymd_opt(2017, 3, 9).unwrap()));
+/// assert_eq!(result.try_get_date("expiry"), Ok(NaiveDate::from_ymd_opt(2030, 12, 31).unwrap()));
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Date {
+    format: Option<String>,
+}
+
+impl Date {
+    /// Construct a `Date` which takes ISO 8601 dates.
+    pub fn new() -> Date {
+        Date::default()
+    }
+
+    /// Take dates in a strftime-style pattern, eg. `%d/%m/%Y`.
+    pub fn format(mut self, pattern: &str) -> Date {
+        self.format = Some(pattern.to_string());
+        self
+    }
+}
+
+impl FieldType for Date {
+    fn from_str(&self, field_name: &str, field_title: &str, value: &str) -> Result<FieldValue, Message> {
+        let pattern = self.format.as_ref().map_or("%Y-%m-%d", |pattern| pattern.as_str());
+        match NaiveDate::parse_from_str(value, pattern) {
+            Ok(date) => Ok(FieldValue::Date(date)),
+            Err(_) => Err(Message::some(MessageKind::Format,
+                                        field_name,
+                                        field_title,
+                                        Some(value.to_string()),
+                                        Vec::new())),
+        }
+    }
+}
+
+/// A field type to represent a time of day, eg. a booking slot.
+///
+/// By default, a time is in ISO 8601, like `09:30` or `09:30:15`, which is
+/// what an HTML time input submits. Call `format` to take a strftime-style
+/// pattern instead.
+#[derive(Clone, Debug, Default)]
+pub struct Time {
+    format: Option<String>,
+}
+
+impl Time {
+    /// Construct a `Time` which takes ISO 8601 times.
+    pub fn new() -> Time {
+        Time::default()
+    }
+
+    /// Take times in a strftime-style pattern, eg. `%I:%M %p`.
+    pub fn format(mut self, pattern: &str) -> Time {
+        self.format = Some(pattern.to_string());
+        self
+    }
+}
+
+impl FieldType for Time {
+    fn from_str(&self, field_name: &str, field_title: &str, value: &str) -> Result<FieldValue, Message> {
+        let time = match self.format {
+            Some(ref pattern) => NaiveTime::parse_from_str(value, pattern),
+            None => NaiveTime::parse_from_str(value, "%H:%M:%S%.f")
+                .or_else(|_| NaiveTime::parse_from_str(value, "%H:%M")),
+        };
+        match time {
+            Ok(time) => Ok(FieldValue::Time(time)),
+            Err(_) => Err(Message::some(MessageKind::Format,
+                                        field_name,
+                                        field_title,
+                                        Some(value.to_string()),
+                                        Vec::new())),
+        }
+    }
+}
+
+/// A field type to represent a date and time, eg. an appointment.
+///
+/// By default, a date and time is in RFC 3339, like `2017-03-09T09:30:00+08:00`,
+/// or in ISO 8601 without an offset, like `2017-03-09T09:30`, which is what an
+/// HTML datetime-local input submits. Call `format` to take a strftime-style
+/// pattern instead.
+///
+/// A value without an offset is taken in the offset given by `offset`,
+/// default UTC.
+///
+/// # Examples
+///
+/// ```
+/// # extern crate form_checker;
+/// # extern crate chrono;
+/// # use form_checker::{Validator, Checker, DateTime};
+/// # use chrono::FixedOffset;
+/// # fn main() {
+/// let beijing = FixedOffset::east_opt(8 * 3600).unwrap();
+/// let mut validator = Validator::new();
+/// validator.check(Checker::new("start", "开始时间", DateTime::new().offset(beijing)));
+///
+/// let mut params = std::collections::HashMap::new();
+/// params.insert("start".to_string(), vec!["2017-03-09T09:30".to_string()]);
+/// let start = validator.evaluate(&params).try_get_datetime("start").unwrap();
+/// assert_eq!(start.to_rfc3339(), "2017-03-09T09:30:00+08:00");
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DateTime {
+    format: Option<String>,
+    offset: FixedOffset,
+}
+
+impl Default for DateTime {
+    fn default() -> DateTime {
+        DateTime::new()
+    }
+}
+
+impl DateTime {
+    /// Construct a `DateTime` which takes RFC 3339 and ISO 8601 values.
+    pub fn new() -> DateTime {
+        DateTime {
+            format: None,
+            offset: FixedOffset::east_opt(0).expect("UTC offset"),
+        }
+    }
+
+    /// Take values in a strftime-style pattern, eg. `%Y/%m/%d %H:%M`.
+    pub fn format(mut self, pattern: &str) -> DateTime {
+        self.format = Some(pattern.to_string());
+        self
+    }
+
+    /// The offset of a value without one.
+    pub fn offset(mut self, offset: FixedOffset) -> DateTime {
+        self.offset = offset;
+        self
+    }
+
+    fn parse(&self, value: &str) -> Option<chrono::DateTime<FixedOffset>> {
+        let naive = match self.format {
+            Some(ref pattern) => {
+                if let Ok(datetime) = chrono::DateTime::parse_from_str(value, pattern) {
+                    return Some(datetime);
+                }
+                NaiveDateTime::parse_from_str(value, pattern).ok()?
+            },
+            None => {
+                if let Ok(datetime) = chrono::DateTime::parse_from_rfc3339(value) {
+                    return Some(datetime);
+                }
+                NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+                    .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M"))
+                    .ok()?
+            },
+        };
+        self.offset.from_local_datetime(&naive).single()
+    }
+}
+
+impl FieldType for DateTime {
+    fn from_str(&self, field_name: &str, field_title: &str, value: &str) -> Result<FieldValue, Message> {
+        match self.parse(value) {
+            Some(datetime) => Ok(FieldValue::DateTime(datetime)),
+            None => Err(Message::some(MessageKind::Format,
+                                      field_name,
+                                      field_title,
+                                      Some(value.to_string()),
+                                      Vec::new())),
+        }
+    }
+}
+
+/// Compare two dates, times, or dates and times, None if they are not of the
+/// same kind.
+pub(crate) fn compare(a: &FieldValue, b: &FieldValue) -> Option<Ordering> {
+    match (a, b) {
+        (FieldValue::Date(a), FieldValue::Date(b)) => Some(a.cmp(b)),
+        (FieldValue::Time(a), FieldValue::Time(b)) => Some(a.cmp(b)),
+        (FieldValue::DateTime(a), FieldValue::DateTime(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+/// Compare a date, or a date and time, with now, None for other values.
+///
+/// A date is compared with today in the local time zone.
+fn compare_now(value: &FieldValue) -> Option<Ordering> {
+    match *value {
+        FieldValue::Date(date) => Some(date.cmp(&Local::now().date_naive())),
+        FieldValue::DateTime(datetime) => datetime.partial_cmp(&Utc::now()),
+        _ => None,
+    }
+}
+
+/// The message for a value a date rule can't apply to, eg. a str value, or a
+/// date with a bound of a date and time.
+fn mismatch(field_name: &str, field_title: &str, raw: &str) -> Message {
+    Message::some(MessageKind::Format, field_name, field_title, Some(raw.to_string()), Vec::new())
+}
+
+pub(crate) fn match_before(bound: &FieldValue, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    match compare(value, bound) {
+        Some(Ordering::Less) => Ok(()),
+        Some(_) => Err(Message::some(MessageKind::Before,
+                                     field_name,
+                                     field_title,
+                                     Some(raw.to_string()),
+                                     vec![bound.to_string()])),
+        None => Err(mismatch(field_name, field_title, raw)),
+    }
+}
+
+pub(crate) fn match_after(bound: &FieldValue, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    match compare(value, bound) {
+        Some(Ordering::Greater) => Ok(()),
+        Some(_) => Err(Message::some(MessageKind::After,
+                                     field_name,
+                                     field_title,
+                                     Some(raw.to_string()),
+                                     vec![bound.to_string()])),
+        None => Err(mismatch(field_name, field_title, raw)),
+    }
+}
+
+pub(crate) fn match_not_in_future(value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    let ordering = compare_now(value).ok_or_else(|| mismatch(field_name, field_title, raw))?;
+    if ordering == Ordering::Greater {
+        return Err(Message::some(MessageKind::Future,
+                                field_name,
+                                field_title,
+                                Some(raw.to_string()),
+                                Vec::new()));
+    }
+    Ok(())
+}
+
+pub(crate) fn match_not_in_past(value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    let ordering = compare_now(value).ok_or_else(|| mismatch(field_name, field_title, raw))?;
+    if ordering == Ordering::Less {
+        return Err(Message::some(MessageKind::Past,
+                                field_name,
+                                field_title,
+                                Some(raw.to_string()),
+                                Vec::new()));
+    }
+    Ok(())
+}
+
+pub(crate) fn match_within(window: chrono::Duration, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    let distance = match *value {
+        FieldValue::Date(date) => date.signed_duration_since(Local::now().date_naive()),
+        FieldValue::DateTime(datetime) => datetime.signed_duration_since(Utc::now()),
+        _ => return Err(mismatch(field_name, field_title, raw)),
+    };
+    if distance.abs() > window.abs() {
+        return Err(Message::some(MessageKind::Within,
+                                field_name,
+                                field_title,
+                                Some(raw.to_string()),
+                                duration_values(window.abs())));
+    }
+    Ok(())
+}
+
+pub(crate) fn match_min_age(age: u32, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    let birthday = match *value {
+        FieldValue::Date(date) => date,
+        FieldValue::DateTime(datetime) => datetime.date_naive(),
+        _ => return Err(mismatch(field_name, field_title, raw)),
+    };
+    if Local::now().date_naive().years_since(birthday).unwrap_or(0) < age {
+        return Err(Message::some(MessageKind::MinAge,
+                                field_name,
+                                field_title,
+                                Some(raw.to_string()),
+                                vec![age.to_string()]));
+    }
+    Ok(())
+}
+
+/// The rule values of a duration, the count and the unit, one of `days`,
+/// `hours`, `minutes` and `seconds`, eg. `30` and `days`.
+fn duration_values(duration: chrono::Duration) -> Vec<String> {
+    let seconds = duration.num_seconds();
+    let (count, unit) = if seconds != 0 && seconds % 86400 == 0 {
+        (seconds / 86400, "days")
+    } else if seconds != 0 && seconds % 3600 == 0 {
+        (seconds / 3600, "hours")
+    } else if seconds != 0 && seconds % 60 == 0 {
+        (seconds / 60, "minutes")
+    } else {
+        (seconds, "seconds")
+    };
+    vec![count.to_string(), unit.to_string()]
+}
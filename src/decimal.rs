@@ -0,0 +1,261 @@
+//! Exact decimal numbers, and the `Decimal` field type for money amounts.
+
+use std::cmp::Ordering;
+use std::error;
+use std::fmt;
+use std::str::FromStr;
+
+use {FieldType, FieldValue, Message, MessageKind, RawValue};
+
+/// The most digits a `DecimalValue` can hold.
+const MAX_DIGITS: usize = 38;
+
+/// An exact decimal number, a mantissa scaled down by a power of ten.
+///
+/// The scale is kept as submitted, so `1.50` has a scale of 2 and displays
+/// as `1.50`, but it still equals `1.5`.
+///
+/// # Examples
+///
+/// ```
+/// # use form_checker::DecimalValue;
+/// let price: DecimalValue = "-12.50".parse().unwrap();
+/// assert_eq!(price, DecimalValue::new(-1250, 2));
+/// assert_eq!(price, DecimalValue::new(-125, 1));
+/// assert_eq!(price.to_string(), "-12.50");
+/// assert_eq!(price.precision(), 4);
+/// assert!(price < DecimalValue::from(0));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DecimalValue {
+    mantissa: i128,
+    scale: u32,
+}
+
+/// The error returned when a str can't be parsed into a `DecimalValue`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseDecimalError;
+
+impl fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid decimal literal")
+    }
+}
+
+impl error::Error for ParseDecimalError {}
+
+impl DecimalValue {
+    /// Construct `mantissa` / 10^`scale`, eg. `DecimalValue::new(1250, 2)` for `12.50`.
+    pub fn new(mantissa: i128, scale: u32) -> DecimalValue {
+        DecimalValue { mantissa, scale }
+    }
+
+    /// The mantissa, eg. 1250 for `12.50`.
+    pub fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// The number of digits after the decimal point, eg. 2 for `12.50`.
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// The number of digits in all, eg. 4 for `12.50` and 2 for `0.05`.
+    pub fn precision(&self) -> u32 {
+        let digits = self.mantissa.unsigned_abs().to_string().len() as u32;
+        digits.max(self.scale).max(1)
+    }
+}
+
+impl From<i64> for DecimalValue {
+    fn from(i: i64) -> DecimalValue {
+        DecimalValue::new(i as i128, 0)
+    }
+}
+
+impl FromStr for DecimalValue {
+    type Err = ParseDecimalError;
+
+    /// Parse a plain decimal literal, like `12`, `-0.5` or `+12.50`.
+    fn from_str(s: &str) -> Result<DecimalValue, ParseDecimalError> {
+        let (negative, s) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (integer, fraction) = match s.find('.') {
+            Some(i) => (&s[..i], &s[i + 1..]),
+            None => (s, ""),
+        };
+        if integer.is_empty() || (s.contains('.') && fraction.is_empty())
+            || !integer.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit()) {
+            return Err(ParseDecimalError);
+        }
+        let digits = format!("{}{}", integer.trim_start_matches('0'), fraction);
+        if digits.len() > MAX_DIGITS {
+            return Err(ParseDecimalError);
+        }
+        let mantissa = if digits.is_empty() { 0 } else { digits.parse::<i128>().map_err(|_| ParseDecimalError)? };
+        Ok(DecimalValue::new(if negative { -mantissa } else { mantissa }, fraction.len() as u32))
+    }
+}
+
+impl fmt::Display for DecimalValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = format!("{:0width$}", self.mantissa.unsigned_abs(), width = self.scale as usize + 1);
+        let point = digits.len() - self.scale as usize;
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        if self.scale == 0 {
+            write!(f, "{}{}", sign, digits)
+        } else {
+            write!(f, "{}{}.{}", sign, &digits[..point], &digits[point..])
+        }
+    }
+}
+
+impl PartialEq for DecimalValue {
+    fn eq(&self, other: &DecimalValue) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for DecimalValue {}
+
+impl PartialOrd for DecimalValue {
+    fn partial_cmp(&self, other: &DecimalValue) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DecimalValue {
+    fn cmp(&self, other: &DecimalValue) -> Ordering {
+        match self.scale.cmp(&other.scale) {
+            Ordering::Equal => self.mantissa.cmp(&other.mantissa),
+            Ordering::Less => match rescale(self.mantissa, other.scale - self.scale) {
+                Some(mantissa) => mantissa.cmp(&other.mantissa),
+                None => self.mantissa.cmp(&0),
+            },
+            Ordering::Greater => other.cmp(self).reverse(),
+        }
+    }
+}
+
+/// mantissa * 10^exponent, None if it overflows, which means it is farther
+/// from zero than any mantissa.
+fn rescale(mantissa: i128, exponent: u32) -> Option<i128> {
+    10i128.checked_pow(exponent).and_then(|factor| mantissa.checked_mul(factor))
+}
+
+/// A field type to represent an exact decimal number, eg. a money amount.
+///
+/// A value like `12`, `-0.5` or `1234.50` is accepted. A currency symbol and
+/// a thousands separator can be allowed, eg. `¥1,234.50`. Use `Rule::Scale`,
+/// `Rule::Precision`, `Rule::MaxDecimal` and `Rule::MinDecimal` to limit it.
+///
+/// # Examples
+///
+/// ```
+/// # use form_checker::{Validator, Checker, Rule, Decimal, DecimalValue};
+/// let mut validator = Validator::new();
+/// validator
+///     .check(Checker::new("amount", "金额", Decimal::new().currency("¥").thousands(','))
+///            .meet(Rule::Scale(2))
+///            .meet(Rule::MinDecimal(DecimalValue::new(1, 2))));
+///
+/// let mut params = std::collections::HashMap::new();
+/// params.insert("amount".to_string(), vec!["¥1,234.50".to_string()]);
+/// assert_eq!(validator.evaluate(&params).try_get_decimal("amount"), Ok(DecimalValue::new(123450, 2)));
+///
+/// params.insert("amount".to_string(), vec!["0".to_string()]);
+/// assert_eq!(validator.evaluate(&params).get_error("amount"), "金额不能小于0.01");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Decimal {
+    currency: Option<String>,
+    thousands: Option<char>,
+}
+
+impl Decimal {
+    /// Construct a `Decimal` which accepts plain decimal literals only.
+    pub fn new() -> Decimal {
+        Decimal::default()
+    }
+
+    /// Allow a currency symbol, eg. `$`, before or after the number.
+    pub fn currency(mut self, symbol: &str) -> Decimal {
+        self.currency = Some(symbol.to_string());
+        self
+    }
+
+    /// Allow a thousands separator, eg. `,`, which must separate every three
+    /// digits of the integer part if used.
+    pub fn thousands(mut self, separator: char) -> Decimal {
+        self.thousands = Some(separator);
+        self
+    }
+
+    fn parse(&self, value: &str) -> Option<DecimalValue> {
+        let (sign, mut number) = split_sign(value);
+        if let Some(ref symbol) = self.currency {
+            if let Some(rest) = number.strip_prefix(symbol.as_str()) {
+                number = rest.trim_start();
+            } else if let Some(rest) = number.strip_suffix(symbol.as_str()) {
+                number = rest.trim_end();
+            }
+        }
+        let (inner_sign, number) = split_sign(number);
+        if !sign.is_empty() && !inner_sign.is_empty() {
+            return None;
+        }
+
+        let number = match self.thousands {
+            Some(separator) if number.contains(separator) => {
+                let (integer, fraction) = match number.find('.') {
+                    Some(i) => number.split_at(i),
+                    None => (number, ""),
+                };
+                let groups: Vec<&str> = integer.split(separator).collect();
+                if groups[0].is_empty() || groups[0].len() > 3 || groups[1..].iter().any(|group| group.len() != 3) {
+                    return None;
+                }
+                format!("{}{}", groups.concat(), fraction)
+            },
+            _ => number.to_string(),
+        };
+        format!("{}{}{}", sign, inner_sign, number).parse().ok()
+    }
+}
+
+fn split_sign(value: &str) -> (&str, &str) {
+    if value.starts_with(['+', '-']) {
+        value.split_at(1)
+    } else {
+        ("", value)
+    }
+}
+
+impl FieldType for Decimal {
+    fn from_str(&self, field_name: &str, field_title: &str, value: &str) -> Result<FieldValue, Message> {
+        match self.parse(value) {
+            Some(decimal) => Ok(FieldValue::Decimal(decimal)),
+            None => Err(Message::some(MessageKind::Format,
+                                      field_name,
+                                      field_title,
+                                      Some(value.to_string()),
+                                      Vec::new())),
+        }
+    }
+
+    fn from_raw(&self, field_name: &str, field_title: &str, value: &RawValue) -> Result<FieldValue, Message> {
+        match *value {
+            RawValue::Str(s) => self.from_str(field_name, field_title, s),
+            RawValue::Int(i) => Ok(FieldValue::Decimal(DecimalValue::from(i))),
+            RawValue::Float(n) => self.from_str(field_name, field_title, &n.to_string()),
+            _ => Err(Message::some(MessageKind::Format,
+                                   field_name,
+                                   field_title,
+                                   Some(value.to_string()),
+                                   Vec::new())),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Filters to clean a raw str value before it is checked, like `Trim`.
+
+use unicode_normalization::UnicodeNormalization;
+
+/// This trait represents a filter, which cleans a raw str value before its
+/// field type sees it, refer to `Checker::filter`.
+///
+/// It is implemented for closures taking a `&str` and returning a `String`,
+/// so you can write your own filter either way.
+///
+/// # Examples
+///
+/// ```
+/// # use form_checker::{Validator, Checker, Email, Trim, Lowercase};
+/// let mut validator = Validator::new();
+/// validator.check(Checker::new("email", "邮箱", Email)
+///                 .filter(Trim)
+///                 .filter(Lowercase)
+///                 .filter(|value: &str| value.replace("@googlemail.com", "@gmail.com")));
+///
+/// let mut params = std::collections::HashMap::new();
+/// params.insert("email".to_string(), vec![" Bob@GoogleMail.com ".to_string()]);
+/// assert_eq!(validator.evaluate(&params).try_get_str("email"), Ok("bob@gmail.com".to_string()));
+/// ```
+pub trait Filter: Send + Sync {
+    fn filter(&self, value: &str) -> String;
+}
+
+impl<F> Filter for F where F: Fn(&str) -> String + Send + Sync {
+    fn filter(&self, value: &str) -> String {
+        self(value)
+    }
+}
+
+/// Remove leading and trailing whitespace.
+pub struct Trim;
+
+impl Filter for Trim {
+    fn filter(&self, value: &str) -> String {
+        value.trim().to_string()
+    }
+}
+
+/// Convert to lowercase, eg. for an email.
+pub struct Lowercase;
+
+impl Filter for Lowercase {
+    fn filter(&self, value: &str) -> String {
+        value.to_lowercase()
+    }
+}
+
+/// Replace each run of whitespace with a single space, and remove leading
+/// and trailing whitespace.
+pub struct CollapseWhitespace;
+
+impl Filter for CollapseWhitespace {
+    fn filter(&self, value: &str) -> String {
+        value.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+}
+
+/// Normalize to Unicode Normalization Form C, so that `é` is the same however
+/// it was typed.
+pub struct Nfc;
+
+impl Filter for Nfc {
+    fn filter(&self, value: &str) -> String {
+        value.nfc().collect()
+    }
+}
+
+/// Normalize to Unicode Normalization Form KC, which also folds compatibility
+/// characters, eg. `①` into `1` and `ﬁ` into `fi`.
+pub struct Nfkc;
+
+impl Filter for Nfkc {
+    fn filter(&self, value: &str) -> String {
+        value.nfkc().collect()
+    }
+}
+
+/// Remove control characters, but keep tabs and line breaks.
+pub struct StripControl;
+
+impl Filter for StripControl {
+    fn filter(&self, value: &str) -> String {
+        value.chars().filter(|&c| !c.is_control() || c == '\t' || c == '\n' || c == '\r').collect()
+    }
+}
+
+/// Convert full-width ASCII characters, which Chinese and Japanese input
+/// methods may type, to their half-width forms, eg. `１２３` into `123`, and
+/// the ideographic space into a space.
+pub struct HalfWidth;
+
+impl Filter for HalfWidth {
+    fn filter(&self, value: &str) -> String {
+        value.chars().map(|c| match c {
+            '\u{3000}' => ' ',
+            '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+            _ => c,
+        }).collect()
+    }
+}
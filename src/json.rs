@@ -0,0 +1,84 @@
+//! JSON values as params, with the `json` feature.
+
+use serde_json::{Map, Value};
+
+use {Children, RawValue, Source};
+
+impl Source for Map<String, Value> {
+    fn get(&self, name: &str) -> Option<Vec<RawValue<'_>>> {
+        match Map::get(self, name) {
+            None | Some(&Value::Null) => None,
+            Some(Value::Array(items)) => Some(items.iter().filter_map(raw_value).collect()),
+            Some(value) => raw_value(value).map(|value| vec![value]),
+        }
+    }
+
+    fn child(&self, name: &str) -> Option<Box<dyn Source + '_>> {
+        match Map::get(self, name) {
+            Some(Value::Object(child)) => Some(Box::new(child)),
+            _ => None,
+        }
+    }
+
+    fn children(&self, name: &str) -> Option<Children<'_>> {
+        let items = match Map::get(self, name) {
+            Some(Value::Array(items)) => items,
+            _ => return None,
+        };
+        let children: Children = items.iter()
+            .enumerate()
+            .filter_map(|(i, item)| match *item {
+                Value::Object(ref item) => Some((i.to_string(), Box::new(item) as Box<dyn Source>)),
+                _ => None,
+            })
+            .collect();
+        if children.is_empty() {
+            return None;
+        }
+        Some(children)
+    }
+}
+
+impl Source for &Map<String, Value> {
+    fn get(&self, name: &str) -> Option<Vec<RawValue<'_>>> {
+        Source::get(*self, name)
+    }
+
+    fn child(&self, name: &str) -> Option<Box<dyn Source + '_>> {
+        (*self).child(name)
+    }
+
+    fn children(&self, name: &str) -> Option<Children<'_>> {
+        (*self).children(name)
+    }
+}
+
+/// A value other than an object is taken as an empty object, so all the
+/// fields are missing.
+impl Source for Value {
+    fn get(&self, name: &str) -> Option<Vec<RawValue<'_>>> {
+        self.as_object().and_then(|map| Source::get(map, name))
+    }
+
+    fn child(&self, name: &str) -> Option<Box<dyn Source + '_>> {
+        self.as_object().and_then(|map| map.child(name))
+    }
+
+    fn children(&self, name: &str) -> Option<Children<'_>> {
+        self.as_object().and_then(|map| map.children(name))
+    }
+}
+
+/// The raw value of a JSON value, None for `null`.
+fn raw_value(value: &Value) -> Option<RawValue<'_>> {
+    match *value {
+        Value::Null => None,
+        Value::Bool(b) => Some(RawValue::Bool(b)),
+        Value::Number(ref n) => Some(match n.as_i64() {
+            Some(i) => RawValue::Int(i),
+            None => RawValue::Float(n.as_f64().unwrap_or(f64::NAN)),
+        }),
+        Value::String(ref s) => Some(RawValue::Str(s)),
+        Value::Array(_) | Value::Object(_) => Some(RawValue::Nested),
+    }
+}
@@ -33,11 +33,42 @@
 //! assert_eq!(validator.get_required("age").as_i64().unwrap(), 20);
 //! ```
 
-extern crate regex;
-
+// A `Message` is returned by value when a check fails, which is not worth
+// boxing for.
+#![allow(clippy::result_large_err)]
+
+pub extern crate chrono;
+pub extern crate regex;
+#[cfg(feature = "json")]
+extern crate serde_json;
+extern crate unicode_normalization;
+extern crate unicode_segmentation;
+extern crate unicode_width;
+
+mod datetime;
+mod decimal;
+mod filter;
+#[cfg(feature = "json")]
+mod json;
+mod multipart;
+mod nested;
+mod urlencoded;
+
+pub use datetime::{Date, DateTime, Time};
+pub use decimal::{Decimal, DecimalValue, ParseDecimalError};
+pub use filter::{CollapseWhitespace, Filter, HalfWidth, Lowercase, Nfc, Nfkc, StripControl, Trim};
+pub use multipart::{multipart_boundary, parse_multipart, File, Form, UploadedFile};
+pub use nested::{join_key, split_key, Children, Group};
+pub use urlencoded::{parse_query, parse_urlencoded, Limits, ParseError};
+
+use std::cmp::Ordering;
 use std::fmt;
 use std::collections::HashMap;
+use std::sync::OnceLock;
 use regex::Regex;
+use chrono::{FixedOffset, NaiveDate, NaiveTime};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// The Validator type.
 ///
@@ -48,20 +79,205 @@ use regex::Regex;
 /// Then, we call its `validator` method to do the validating thing.
 ///
 /// Finally, we get valid keys and values from its `valid_data` member and get invalid
-/// keys and messages from  its `invalid_messages` member.
+/// keys and messages from  its `invalid_messages` member, which keeps the first
+/// message of each field. All the messages collected with
+/// `CheckerOption::CollectAll` are got by `get_field_errors`.
+///
+/// Alternatively, we call its `evaluate` method, which leaves the validator
+/// untouched and returns the outcome as a `ValidationResult`.
 ///
 /// The `message_renderer` member is used to custom invalid messages.
+///
+/// The `limits` member is used when decoding a URL-encoded input, refer to
+/// `validate_query`.
 pub struct Validator<T: MessageRenderer=()> {
-    pub checkers: Vec<Box<Checkable>>,
-    pub valid_data: HashMap<String, Option<Vec<FieldValue>>>,
-    pub invalid_messages: HashMap<String, String>,
+    pub checkers: Vec<Box<dyn Checkable>>,
+    pub valid_data: ValidData,
+    pub invalid_messages: InvalidMessages,
     pub message_renderer: T,
+    pub limits: Limits,
+    empty_policy: EmptyPolicy,
+    field_errors: FieldErrors,
+}
+
+/// Valid values by field name, `None` for a missing optional field.
+pub type ValidData = HashMap<String, Option<Vec<FieldValue>>>;
+
+/// The first rendered invalid message by field name.
+pub type InvalidMessages = HashMap<String, String>;
+
+/// All the rendered invalid messages by field name.
+type FieldErrors = HashMap<String, Vec<InvalidMessage>>;
+
+/// The outcome of checking a field, the valid values or the messages telling
+/// why it is invalid.
+pub type CheckResult = Result<Option<Vec<FieldValue>>, Vec<Message>>;
+
+/// A rendered message for an invalid field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InvalidMessage {
+    /// The index of the offending value for a multiple field, None otherwise.
+    pub index: Option<usize>,
+    /// The rendered message.
+    pub text: String,
+}
+
+/// A raw value submitted for a field, before being turned into a `FieldValue`
+/// by a `FieldType`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RawValue<'a> {
+    /// A text value, eg. from a query string.
+    Str(&'a str),
+    /// An uploaded file, from a multipart body.
+    File(&'a UploadedFile),
+    /// An integer, eg. from a JSON body.
+    Int(i64),
+    /// A number which is not an integer, eg. from a JSON body.
+    Float(f64),
+    /// A boolean, eg. from a JSON body.
+    Bool(bool),
+    /// An object or array found where a plain value is expected, eg. in a
+    /// JSON body.
+    Nested,
+}
+
+impl<'a> RawValue<'a> {
+    /// True if this is an empty or whitespace-only str value.
+    pub fn is_empty(&self) -> bool {
+        match *self {
+            RawValue::Str(s) => s.trim().is_empty(),
+            _ => false,
+        }
+    }
+}
+
+impl<'a> fmt::Display for RawValue<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RawValue::Str(s) => write!(f, "{}", s),
+            RawValue::File(file) => write!(f, "{}", file.filename.as_ref().map_or("", |name| name.as_str())),
+            RawValue::Int(i) => write!(f, "{}", i),
+            RawValue::Float(n) => write!(f, "{}", n),
+            RawValue::Bool(b) => write!(f, "{}", b),
+            RawValue::Nested => Ok(()),
+        }
+    }
+}
+
+/// Where checkers look up the raw values of a field by its name.
+///
+/// It is implemented for the params as a `HashMap<String, Vec<String>>`, and
+/// for a `Form` decoded from a multipart body. Both understand bracketed
+/// field names, refer to `Group`. With the `json` feature, it is also
+/// implemented for a JSON value, refer to `Validator::validate_json`. A
+/// `&dyn Source` is a source as well.
+pub trait Source {
+    /// The raw values of a field, None if the field is missing.
+    fn get(&self, name: &str) -> Option<Vec<RawValue<'_>>>;
+
+    /// The nested params of a field, eg. `user[...]`, None if missing.
+    fn child(&self, _name: &str) -> Option<Box<dyn Source + '_>> {
+        None
+    }
+
+    /// The nested params of a repeated field, eg. `items[0][...]`, None if missing.
+    fn children(&self, _name: &str) -> Option<Children<'_>> {
+        None
+    }
+}
+
+impl Source for HashMap<String, Vec<String>> {
+    fn get(&self, name: &str) -> Option<Vec<RawValue<'_>>> {
+        nested::lookup(self, name).map(|values| values.into_iter().map(|v| RawValue::Str(v)).collect())
+    }
+
+    fn child(&self, name: &str) -> Option<Box<dyn Source + '_>> {
+        let child = nested::child_map(self, name);
+        if child.is_empty() {
+            return None;
+        }
+        Some(Box::new(child))
+    }
+
+    fn children(&self, name: &str) -> Option<Children<'_>> {
+        let items = nested::item_maps(self, name);
+        if items.is_empty() {
+            return None;
+        }
+        Some(items.into_iter().map(|(i, item)| (i.to_string(), Box::new(item) as Box<dyn Source>)).collect())
+    }
+}
+
+impl Source for &dyn Source {
+    fn get(&self, name: &str) -> Option<Vec<RawValue<'_>>> {
+        (**self).get(name)
+    }
+
+    fn child(&self, name: &str) -> Option<Box<dyn Source + '_>> {
+        (**self).child(name)
+    }
+
+    fn children(&self, name: &str) -> Option<Children<'_>> {
+        (**self).children(name)
+    }
 }
 
 /// Represents a type to fed to the Validator.
-pub trait Checkable {
-    fn check(&self, params: &HashMap<String, Vec<String>>) -> Result<Option<Vec<FieldValue>>, Message>;
+///
+/// Checkables are required to be `Send + Sync`, so that a validator can be
+/// built once and shared between threads.
+pub trait Checkable: Send + Sync {
+    fn check(&self, params: &dyn Source) -> CheckResult;
     fn get_name(&self) -> String;
+
+    /// Check all the fields this checkable covers, and push the outcome of
+    /// each one with its full path to `out`.
+    ///
+    /// siblings are the checkables next to this one, in the same group or at
+    /// the top level, and path is the path of the enclosing `Group`, empty at
+    /// the top level. By default, there is only the outcome of
+    /// `check_with_siblings`.
+    fn check_fields(&self, params: &dyn Source, siblings: &[Box<dyn Checkable>], path: &str, out: &mut Vec<(String, CheckResult)>) {
+        out.push((nested::join_path(path, &self.get_name()), self.check_with_siblings(params, siblings)));
+    }
+
+    /// Check this field knowing the checkables next to it, eg. to look at the
+    /// filtered values of another field, refer to `CheckerOption::RequiredIf`.
+    /// By default, it is `check`.
+    fn check_with_siblings(&self, params: &dyn Source, _siblings: &[Box<dyn Checkable>]) -> CheckResult {
+        self.check(params)
+    }
+
+    /// Clean a str value of this field as it is before being checked, refer
+    /// to `Checker::filter`. By default, it is kept as is.
+    fn filter_value(&self, value: &str) -> String {
+        value.to_string()
+    }
+
+    /// Check the default values of all the fields this checkable covers,
+    /// refer to `CheckerOption::Default`. By default, there are none.
+    fn check_default(&self) -> Result<(), Message> {
+        Ok(())
+    }
+
+    /// Take the empty policy of the validator, for the fields without their
+    /// own, refer to `Validator::empty_policy`. By default, it is ignored.
+    fn inherit_empty_policy(&mut self, _policy: EmptyPolicy) {}
+
+    /// The title, used to display invalid messages about other fields. By
+    /// default, the name.
+    fn get_title(&self) -> String {
+        self.get_name()
+    }
+
+    /// Meet the rules referencing other fields, refer to `Rule::EqualsField`,
+    /// once all the fields are checked, and push the messages with the full
+    /// path of the field to `out`.
+    ///
+    /// siblings are the checkables next to this one, in the same group or at
+    /// the top level, and data is the valid data of all the fields. By
+    /// default, there are no such rules.
+    fn check_cross_fields(&self, _siblings: &[Box<dyn Checkable>], _path: &str, _data: &ValidData, _out: &mut Vec<(String, Message)>) {}
 }
 
 impl Validator<()> {
@@ -71,6 +287,12 @@ impl Validator<()> {
     }
 }
 
+impl Default for Validator<()> {
+    fn default() -> Validator<()> {
+        Validator::new()
+    }
+}
+
 impl<T: MessageRenderer> Validator<T> {
     /// Constructs a new `Validator` with a custom message renderer.
     ///
@@ -95,6 +317,7 @@ impl<T: MessageRenderer> Validator<T> {
     ///             MessageKind::MinLen => format!("{title} can't be shorter than {rule}", title=m.title, rule=m.rule_values[0]),
     ///             MessageKind::Blank => format!("{title} is missing", title=m.title),
     ///             MessageKind::Format => format!("{title} is in wrong format", title=m.title),
+    ///             _ => format!("{title} is invalid", title=m.title),
     ///         }
     ///     }
     /// }
@@ -105,7 +328,10 @@ impl<T: MessageRenderer> Validator<T> {
             checkers: Vec::new(),
             valid_data: HashMap::new(),
             invalid_messages: HashMap::new(),
-            message_renderer: message_renderer,
+            message_renderer,
+            limits: Limits::default(),
+            empty_policy: EmptyPolicy::default(),
+            field_errors: HashMap::new(),
         }
     }
 
@@ -127,26 +353,259 @@ impl<T: MessageRenderer> Validator<T> {
     ///            .meet(Rule::Max(100))
     ///            .meet(Rule::Min(18)));
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a default value of the checker is invalid, refer to
+    /// `CheckerOption::Default`. Use `try_check` if the default value is not
+    /// known to be valid, eg. when it comes from configuration.
     pub fn check<U: Checkable + 'static>(&mut self, checker: U) -> &mut Validator<T> {
+        if let Err(message) = self.try_check(checker) {
+            panic!("invalid default value: {}", self.message_renderer.render(message));
+        }
+        self
+    }
+
+    /// Add a checker to this validator, or tell why a default value of the
+    /// checker is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, CheckerOption, Rule, Message, MessageKind, I64};
+    /// let mut validator = Validator::new();
+    /// let result = validator.try_check(Checker::new("page", "页码", I64)
+    ///                                  .set(CheckerOption::Default("0".to_string()))
+    ///                                  .meet(Rule::Min(1)));
+    /// match result {
+    ///     Err(Message::Some(m)) => assert!(matches!(m.kind, MessageKind::Min)),
+    ///     _ => panic!("the default value is invalid"),
+    /// }
+    /// assert!(validator.checkers.is_empty());
+    /// ```
+    pub fn try_check<U: Checkable + 'static>(&mut self, mut checker: U) -> Result<&mut Validator<T>, Message> {
+        checker.check_default()?;
+        checker.inherit_empty_policy(self.empty_policy);
         self.checkers.push(Box::new(checker));
+        Ok(self)
+    }
+
+    /// Set how an empty or whitespace-only str value is treated, for the
+    /// checkers, added before or after, without their own policy, default
+    /// `EmptyPolicy::Value`, refer to `CheckerOption::Empty`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, EmptyPolicy, Str};
+    /// let mut params = std::collections::HashMap::new();
+    /// params.insert("name".to_string(), vec!["".to_string()]);
+    ///
+    /// let mut validator = Validator::new();
+    /// validator
+    ///     .empty_policy(EmptyPolicy::Missing)
+    ///     .check(Checker::new("name", "姓名", Str));
+    /// assert_eq!(validator.evaluate(&params).get_error("name"), "姓名不能为空");
+    /// ```
+    pub fn empty_policy(&mut self, policy: EmptyPolicy) -> &mut Validator<T> {
+        self.empty_policy = policy;
+        for checker in &mut self.checkers {
+            checker.inherit_empty_policy(policy);
+        }
         self
     }
 
     /// Do the validating logic.
     ///
     /// Don't forget to add checkers first.
-    pub fn validate(&mut self, params: &HashMap<String, Vec<String>>) {
+    ///
+    /// The outcome is stored in `valid_data` and `invalid_messages`, so call
+    /// `reset` before validating other params. Use `evaluate` if you want to
+    /// share one validator between requests.
+    pub fn validate<S: Source>(&mut self, params: &S) {
+        let result = self.evaluate(params);
+        self.valid_data.extend(result.valid_data);
+        self.invalid_messages.extend(result.invalid_messages);
+        self.field_errors.extend(result.field_errors);
+    }
+
+    /// Do the validating logic without touching the validator.
+    ///
+    /// The valid values and invalid messages are returned as a
+    /// `ValidationResult`, which offers the same getters as the validator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, Rule, Str};
+    /// let mut validator = Validator::new();
+    /// validator
+    ///     .check(Checker::new("name", "姓名", Str)
+    ///            .meet(Rule::Max(5))
+    ///            .meet(Rule::Min(2)));
+    ///
+    /// let mut params = std::collections::HashMap::new();
+    /// params.insert("name".to_string(), vec!["bob".to_string()]);
+    /// let result = validator.evaluate(&params);
+    /// assert!(result.is_valid());
+    /// assert_eq!(result.get_required("name").as_str().unwrap(), "bob".to_string());
+    ///
+    /// params.insert("name".to_string(), vec!["b".to_string()]);
+    /// let result = validator.evaluate(&params);
+    /// assert!(!result.is_valid());
+    /// assert_eq!(result.get_error("name"), "姓名长度不能小于2");
+    /// ```
+    pub fn evaluate<S: Source>(&self, params: &S) -> ValidationResult {
+        let mut outcomes = Vec::new();
         for checker in &self.checkers {
-            match checker.check(params) {
+            checker.check_fields(params, &self.checkers, "", &mut outcomes);
+        }
+
+        let mut result = ValidationResult::new();
+        for (name, outcome) in outcomes {
+            match outcome {
                 Ok(v) => {
-                    self.valid_data.insert(checker.get_name().clone(), v);
+                    result.valid_data.insert(name, v);
                 },
-                Err(msg) => {
-                    self.invalid_messages.insert(checker.get_name().clone(),
-                                                 self.message_renderer.render(msg));
+                Err(mut messages) => {
+                    // A checkable may fail without telling why.
+                    if messages.is_empty() {
+                        messages.push(Message::some(MessageKind::Format, &name, &name, None, Vec::new()));
+                    }
+                    let messages: Vec<_> = messages.into_iter().map(|m| InvalidMessage {
+                        index: m.index(),
+                        text: self.message_renderer.render(m),
+                    }).collect();
+                    result.invalid_messages.insert(name.clone(), messages[0].text.clone());
+                    result.field_errors.insert(name, messages);
                 },
             }
         }
+
+        let mut messages = Vec::new();
+        for checker in &self.checkers {
+            checker.check_cross_fields(&self.checkers, "", &result.valid_data, &mut messages);
+        }
+        for (name, message) in messages {
+            result.valid_data.remove(&name);
+            let message = InvalidMessage {
+                index: message.index(),
+                text: self.message_renderer.render(message),
+            };
+            result.invalid_messages.entry(name.clone()).or_insert_with(|| message.text.clone());
+            result.field_errors.entry(name).or_default().push(message);
+        }
+        result
+    }
+
+    /// Decode a query string and validate it, without touching the validator.
+    ///
+    /// Refer to `parse_query` and `evaluate`. An error is returned if the
+    /// query string breaks the `limits` of this validator, or is not valid
+    /// UTF-8 once decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, CheckerOption, Str, I64};
+    /// let mut validator = Validator::new();
+    /// validator
+    ///     .check(Checker::new("name", "姓名", Str))
+    ///     .check(Checker::new("tag", "标签", I64)
+    ///            .set(CheckerOption::Multiple(true)));
+    ///
+    /// let result = validator.validate_query("?name=bob+lee&tag=1&tag=2").unwrap();
+    /// assert_eq!(result.get_required("name").as_str().unwrap(), "bob lee".to_string());
+    /// assert_eq!(result.get_required_multiple("tag").len(), 2);
+    /// ```
+    pub fn validate_query(&self, query: &str) -> Result<ValidationResult, ParseError> {
+        Ok(self.evaluate(&parse_query(query, &self.limits)?))
+    }
+
+    /// Decode a URL-encoded form body and validate it, without touching the
+    /// validator.
+    ///
+    /// Refer to `validate_query`.
+    pub fn validate_urlencoded(&self, body: &[u8]) -> Result<ValidationResult, ParseError> {
+        Ok(self.evaluate(&parse_urlencoded(body, &self.limits)?))
+    }
+
+    /// Decode a `multipart/form-data` body and validate it, without touching
+    /// the validator.
+    ///
+    /// content_type is the content type header of the request, which gives
+    /// the boundary. Refer to `parse_multipart` and `File`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, Str, File};
+    /// let mut validator = Validator::new();
+    /// validator
+    ///     .check(Checker::new("name", "姓名", Str))
+    ///     .check(Checker::new("avatar", "头像", File::new().extensions(&["txt"])));
+    ///
+    /// let body = b"--XyZ\r\n\
+    ///              Content-Disposition: form-data; name=\"name\"\r\n\r\n\
+    ///              bob\r\n\
+    ///              --XyZ\r\n\
+    ///              Content-Disposition: form-data; name=\"avatar\"; filename=\"a.txt\"\r\n\r\n\
+    ///              hello\r\n\
+    ///              --XyZ--\r\n";
+    /// let result = validator.validate_multipart(body, "multipart/form-data; boundary=XyZ").unwrap();
+    /// assert_eq!(result.get_required("name").as_str().unwrap(), "bob".to_string());
+    /// assert_eq!(result.get_required("avatar").as_file().unwrap().data, b"hello".to_vec());
+    /// ```
+    pub fn validate_multipart(&self, body: &[u8], content_type: &str) -> Result<ValidationResult, ParseError> {
+        let boundary = match multipart_boundary(content_type) {
+            Some(boundary) => boundary,
+            None => return Err(ParseError::Malformed("no multipart boundary".to_string())),
+        };
+        Ok(self.evaluate(&parse_multipart(body, boundary, &self.limits)?))
+    }
+
+    /// Validate a JSON value, without touching the validator.
+    ///
+    /// This needs the `json` feature. The value is expected to be an object,
+    /// whose members are looked up by the checkers. An array gives the values
+    /// of a multiple field, and an object or an array of objects is looked up
+    /// by a `Group`. A `null` member is missing.
+    ///
+    /// Numbers and booleans are handed to the field types as they are, so
+    /// `I64` takes `20` but not `20.5` nor `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # extern crate form_checker;
+    /// # extern crate serde_json;
+    /// # use form_checker::{Validator, Checker, CheckerOption, Group, Rule, Str, I64};
+    /// # fn main() {
+    /// let mut validator = Validator::new();
+    /// validator
+    ///     .check(Checker::new("name", "姓名", Str))
+    ///     .check(Checker::new("tag", "标签", Str)
+    ///            .set(CheckerOption::Multiple(true)))
+    ///     .check(Group::new("items", "明细")
+    ///            .set(CheckerOption::Multiple(true))
+    ///            .check(Checker::new("qty", "数量", I64)
+    ///                   .meet(Rule::Min(1))));
+    ///
+    /// let value = serde_json::json!({
+    ///     "name": "bob",
+    ///     "tag": ["a", "b"],
+    ///     "items": [{"qty": 1}, {"qty": 0}],
+    /// });
+    /// let result = validator.validate_json(&value);
+    /// assert_eq!(result.get_required("name").as_str().unwrap(), "bob".to_string());
+    /// assert_eq!(result.get_required_multiple("tag").len(), 2);
+    /// assert_eq!(result.get_required("items[0][qty]").as_i64().unwrap(), 1);
+    /// assert_eq!(result.get_error("items[1][qty]"), "数量不能小于1");
+    /// # }
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn validate_json(&self, value: &serde_json::Value) -> ValidationResult {
+        self.evaluate(value)
     }
 
     /// Get a required valid value after validating.
@@ -156,7 +615,8 @@ impl<T: MessageRenderer> Validator<T> {
     /// Call this method when you're sure the value exists and is valid, or it panics!
     ///
     /// You may want to call `is_valid` method first, when that is true,
-    /// you have confidence that this call will not panic!
+    /// you have confidence that this call will not panic! Or call
+    /// `try_get_required` instead, which never panics.
     ///
     /// # Examples
     ///
@@ -175,7 +635,7 @@ impl<T: MessageRenderer> Validator<T> {
     /// assert_eq!(validator.get_required("name").as_str().unwrap(), "bob".to_string());
     /// ```
     pub fn get_required(&self, name: &str) -> FieldValue {
-        self.valid_data.get(name).unwrap().as_ref().unwrap()[0].clone()
+        or_panic(self.view().required(name))
     }
 
     /// Get a optional valid value after validating.
@@ -207,10 +667,7 @@ impl<T: MessageRenderer> Validator<T> {
     /// assert!(validator.get_optional("name").is_none());
     /// ```
     pub fn get_optional(&self, name: &str) -> Option<FieldValue> {
-        match self.valid_data.get(name).unwrap().as_ref() {
-            Some(v) => Some(v[0].clone()),
-            None => None,
-        }
+        or_panic(self.view().optional(name))
     }
 
     /// Get multiple valid values after validating.
@@ -244,8 +701,8 @@ impl<T: MessageRenderer> Validator<T> {
     /// assert_eq!(validator.get_required_multiple("name").iter().map(|item| item.as_str().unwrap()).collect::<Vec<_>>(), vec!["bob".to_string(), "mary".to_string()]);
     /// ```
     pub fn get_required_multiple(&self, name: &str) -> Vec<FieldValue> {
-        self.valid_data.get(name).unwrap().as_ref().unwrap().clone()
-     }
+        or_panic(self.view().required_multiple(name))
+    }
 
     /// Get optional multiple valid values after validating.
     ///
@@ -279,13 +736,14 @@ impl<T: MessageRenderer> Validator<T> {
     /// assert_eq!(validator.get_optional_multiple("name").unwrap().iter().map(|item| item.as_str().unwrap()).collect::<Vec<_>>(), vec!["bob".to_string(), "mary".to_string()]);
     /// ```
     pub fn get_optional_multiple(&self, name: &str) -> Option<Vec<FieldValue>> {
-        self.valid_data.get(name).unwrap().clone()
+        or_panic(self.view().optional_multiple(name))
     }
 
     /// Tell you whether the validator is valid or not, you must first call
     /// `validate` method.
     pub fn is_valid(&self) -> bool {
-        self.valid_data.len() == self.checkers.len()
+        self.invalid_messages.is_empty() &&
+            self.checkers.iter().all(|checker| self.valid_data.contains_key(&checker.get_name()))
     }
 
     /// Get an error message by field name.
@@ -312,7 +770,20 @@ impl<T: MessageRenderer> Validator<T> {
     /// assert_eq!(validator.get_error("name"), "姓名长度不能小于2");
     /// ```
     pub fn get_error(&self, name: &str) -> String {
-        self.invalid_messages.get(name).unwrap().clone()
+        or_panic(self.view().error(name))
+    }
+
+    /// Get all error messages of a field.
+    ///
+    /// There is only one message unless `CheckerOption::CollectAll` is set on
+    /// the checker. Refer to `CheckerOption::CollectAll`.
+    ///
+    /// # Panics
+    ///
+    /// Make sure you know this field is invalid before you get its messages,
+    /// or it panics.
+    pub fn get_field_errors(&self, name: &str) -> Vec<InvalidMessage> {
+        or_panic(self.view().field_errors(name))
     }
 
     /// Get all error messages as a vector.
@@ -334,7 +805,7 @@ impl<T: MessageRenderer> Validator<T> {
     /// assert_eq!(validator.get_errors(), vec!["姓名长度不能小于2"]);
     /// ```
     pub fn get_errors(&self) -> Vec<String> {
-        self.invalid_messages.values().map(|e|e.clone()).collect()
+        self.view().errors()
     }
 
     /// Get an arbitrary error message.
@@ -361,103 +832,706 @@ impl<T: MessageRenderer> Validator<T> {
     /// assert_eq!(validator.get_some_error(), "姓名长度不能小于2");
     /// ```
     pub fn get_some_error(&self) -> String {
-        self.invalid_messages.values().next().unwrap().clone()
+        self.view().some_error().expect("no invalid field")
     }
 
     /// Clear the valid_data and invalid_messages, as if you have not called `validate`.
     pub fn reset(&mut self) {
         self.valid_data.clear();
         self.invalid_messages.clear();
+        self.field_errors.clear();
     }
-}
-
-/// This enum is used to mark a type of a `Message`
-pub enum MessageKind {
-    /// Greater than maximum value, eg. for an int value.
-    Max,
-    /// Less than mininum value, eg. for an int value.
-    Min,
-    /// Longer than maximum lenghth, eg. for a string value.
-    MaxLen,
-    /// Shorter than minimum length, eg. for a string value.
-    MinLen,
-    /// Value required, but missing.
-    Blank,
-    /// Value not match some format.
-    Format,
-}
-
-/// A general message wrapper
-pub enum Message {
-    /// A customized message, just show any message given.
-    Any(String),
-    /// A kind of message predefined.
-    Some(SomeMessage),
-}
-
-/// A specific message
-pub struct SomeMessage {
-    /// Refer to `MessageKind`.
-    pub kind: MessageKind,
-    /// The field name.
-    pub name: String,
-    /// The field title.
-    pub title: String,
-    /// The field raw value, None if missing.
-    pub value: Option<String>,
-    /// rule related values, such as max and min, as strings.
-    pub rule_values: Vec<String>,
-}
 
-impl Message {
-    /// Construct a kind of message.
-    pub fn some(kind: MessageKind, name: &str, title: &str, value: Option<String>, rule_values: Vec<String>) -> Message {
-        Message::Some(SomeMessage {
-            kind: kind,
-            name: name.to_string(),
-            title: title.to_string(),
-            value: value,
-            rule_values: rule_values,
-        })
+    /// Get a required valid value, without panicking.
+    ///
+    /// It's like `get_required`, but tells you why there is no such value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, CheckerOption, Rule, Str, GetError};
+    /// let mut params = std::collections::HashMap::new();
+    /// params.insert("name".to_string(), vec!["b".to_string()]);
+    ///
+    /// let mut validator = Validator::new();
+    /// validator
+    ///     .check(Checker::new("name", "姓名", Str)
+    ///            .meet(Rule::Min(2)))
+    ///     .check(Checker::new("nick", "昵称", Str)
+    ///            .set(CheckerOption::Optional(true)));
+    /// validator.validate(&params);
+    /// assert_eq!(validator.try_get_required("name"), Err(GetError::Invalid("name".to_string())));
+    /// assert_eq!(validator.try_get_required("nick"), Err(GetError::Absent("nick".to_string())));
+    /// assert_eq!(validator.try_get_required("nmae"), Err(GetError::Unknown("nmae".to_string())));
+    /// ```
+    pub fn try_get_required(&self, name: &str) -> Result<FieldValue, GetError> {
+        self.view().required(name)
     }
 
-    /// Construct a customized message.
-    pub fn any(message: &str) -> Message {
-        Message::Any(message.to_string())
+    /// Get an optional valid value, without panicking, refer to `get_optional`.
+    pub fn try_get_optional(&self, name: &str) -> Result<Option<FieldValue>, GetError> {
+        self.view().optional(name)
     }
-}
 
-/// If you want to control how the message is displayed, implement this trait.
-///
-/// The default implementation is in simple Chinese.
-pub trait MessageRenderer {
-    fn render_message(&self, m: SomeMessage) -> String {
-        match m.kind {
-            MessageKind::Max => format!("{title}不能大于{rule}", title=m.title, rule=m.rule_values[0]),
-            MessageKind::Min => format!("{title}不能小于{rule}", title=m.title, rule=m.rule_values[0]),
-            MessageKind::MaxLen => format!("{title}长度不能大于{rule}", title=m.title, rule=m.rule_values[0]),
-            MessageKind::MinLen => format!("{title}长度不能小于{rule}", title=m.title, rule=m.rule_values[0]),
-            MessageKind::Blank => format!("{title}不能为空", title=m.title),
-            MessageKind::Format => format!("{title}格式不正确", title=m.title),
-        }
+    /// Get required multiple valid values, without panicking, refer to
+    /// `get_required_multiple`.
+    pub fn try_get_required_multiple(&self, name: &str) -> Result<Vec<FieldValue>, GetError> {
+        self.view().required_multiple(name)
     }
-}
-
-trait Renderable {
-    fn render(&self, m:Message) -> String;
-}
 
-impl<T:MessageRenderer> Renderable for T {
-    fn render(&self, m: Message) -> String {
-        match m {
-            Message::Any(s) => s,
-            Message::Some(km) => self.render_message(km),
-        }
+    /// Get optional multiple valid values, without panicking, refer to
+    /// `get_optional_multiple`.
+    pub fn try_get_optional_multiple(&self, name: &str) -> Result<Option<Vec<FieldValue>>, GetError> {
+        self.view().optional_multiple(name)
     }
-}
 
-impl MessageRenderer for () {
-}
+    /// Get a required str value, without panicking.
+    ///
+    /// `GetError::Mismatch` is returned if the value is not a `FieldValue::Str`.
+    pub fn try_get_str(&self, name: &str) -> Result<String, GetError> {
+        self.view().str(name)
+    }
+
+    /// Get a required i64 value, without panicking.
+    ///
+    /// `GetError::Mismatch` is returned if the value is not a `FieldValue::I64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, I64, GetError};
+    /// let mut params = std::collections::HashMap::new();
+    /// params.insert("age".to_string(), vec!["20".to_string()]);
+    ///
+    /// let mut validator = Validator::new();
+    /// validator.check(Checker::new("age", "年龄", I64));
+    /// validator.validate(&params);
+    /// assert_eq!(validator.try_get_i64("age"), Ok(20));
+    /// assert_eq!(validator.try_get_str("age"), Err(GetError::Mismatch("age".to_string())));
+    /// ```
+    pub fn try_get_i64(&self, name: &str) -> Result<i64, GetError> {
+        self.view().i64(name)
+    }
+
+    /// Get a required f64 value, without panicking.
+    ///
+    /// `GetError::Mismatch` is returned if the value is not a `FieldValue::F64`.
+    pub fn try_get_f64(&self, name: &str) -> Result<f64, GetError> {
+        self.view().f64(name)
+    }
+
+    /// Get a required decimal value, without panicking.
+    ///
+    /// `GetError::Mismatch` is returned if the value is not a `FieldValue::Decimal`.
+    pub fn try_get_decimal(&self, name: &str) -> Result<DecimalValue, GetError> {
+        self.view().decimal(name)
+    }
+
+    /// Get a required bool value, without panicking.
+    ///
+    /// `GetError::Mismatch` is returned if the value is not a `FieldValue::Bool`.
+    pub fn try_get_bool(&self, name: &str) -> Result<bool, GetError> {
+        self.view().bool(name)
+    }
+
+    /// Get a required date, without panicking.
+    ///
+    /// `GetError::Mismatch` is returned if the value is not a `FieldValue::Date`.
+    pub fn try_get_date(&self, name: &str) -> Result<NaiveDate, GetError> {
+        self.view().date(name)
+    }
+
+    /// Get a required time of day, without panicking.
+    ///
+    /// `GetError::Mismatch` is returned if the value is not a `FieldValue::Time`.
+    pub fn try_get_time(&self, name: &str) -> Result<NaiveTime, GetError> {
+        self.view().time(name)
+    }
+
+    /// Get a required date and time, without panicking.
+    ///
+    /// `GetError::Mismatch` is returned if the value is not a `FieldValue::DateTime`.
+    pub fn try_get_datetime(&self, name: &str) -> Result<chrono::DateTime<FixedOffset>, GetError> {
+        self.view().datetime(name)
+    }
+
+    /// Get a required uploaded file, without panicking.
+    ///
+    /// `GetError::Mismatch` is returned if the value is not a `FieldValue::File`.
+    pub fn try_get_file(&self, name: &str) -> Result<UploadedFile, GetError> {
+        self.view().file(name)
+    }
+
+    /// Get an error message by field name, without panicking, refer to `get_error`.
+    ///
+    /// `GetError::Valid` is returned if the field is valid.
+    pub fn try_get_error(&self, name: &str) -> Result<String, GetError> {
+        self.view().error(name)
+    }
+
+    /// Get all error messages of a field, without panicking, refer to
+    /// `get_field_errors`.
+    pub fn try_get_field_errors(&self, name: &str) -> Result<Vec<InvalidMessage>, GetError> {
+        self.view().field_errors(name)
+    }
+
+    /// Get an arbitrary error message, None if there is no invalid field.
+    pub fn try_get_some_error(&self) -> Option<String> {
+        self.view().some_error()
+    }
+
+    fn view(&self) -> View<'_> {
+        View {
+            valid_data: &self.valid_data,
+            invalid_messages: &self.invalid_messages,
+            field_errors: &self.field_errors,
+        }
+    }
+}
+
+/// The outcome of validating some params, returned by `Validator::evaluate`.
+///
+/// It owns the valid values and the rendered invalid messages, so the
+/// validator itself is left untouched and can be reused as is.
+pub struct ValidationResult {
+    pub valid_data: ValidData,
+    pub invalid_messages: InvalidMessages,
+    field_errors: FieldErrors,
+}
+
+impl ValidationResult {
+    fn new() -> ValidationResult {
+        ValidationResult {
+            valid_data: HashMap::new(),
+            invalid_messages: HashMap::new(),
+            field_errors: HashMap::new(),
+        }
+    }
+
+    /// Get a required valid value, refer to `Validator::get_required`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is missing or invalid.
+    pub fn get_required(&self, name: &str) -> FieldValue {
+        or_panic(self.view().required(name))
+    }
+
+    /// Get an optional valid value, refer to `Validator::get_optional`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is invalid.
+    pub fn get_optional(&self, name: &str) -> Option<FieldValue> {
+        or_panic(self.view().optional(name))
+    }
+
+    /// Get required multiple valid values, refer to `Validator::get_required_multiple`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the values are missing or invalid.
+    pub fn get_required_multiple(&self, name: &str) -> Vec<FieldValue> {
+        or_panic(self.view().required_multiple(name))
+    }
+
+    /// Get optional multiple valid values, refer to `Validator::get_optional_multiple`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the values are invalid.
+    pub fn get_optional_multiple(&self, name: &str) -> Option<Vec<FieldValue>> {
+        or_panic(self.view().optional_multiple(name))
+    }
+
+    /// Tell you whether all the fields are valid.
+    pub fn is_valid(&self) -> bool {
+        self.invalid_messages.is_empty()
+    }
+
+    /// Get an error message by field name, refer to `Validator::get_error`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field is not invalid.
+    pub fn get_error(&self, name: &str) -> String {
+        or_panic(self.view().error(name))
+    }
+
+    /// Get all error messages of a field, refer to `Validator::get_field_errors`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field is not invalid.
+    pub fn get_field_errors(&self, name: &str) -> Vec<InvalidMessage> {
+        or_panic(self.view().field_errors(name))
+    }
+
+    /// Get all error messages as a vector.
+    pub fn get_errors(&self) -> Vec<String> {
+        self.view().errors()
+    }
+
+    /// Get an arbitrary error message.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no invalid field.
+    pub fn get_some_error(&self) -> String {
+        self.view().some_error().expect("no invalid field")
+    }
+
+    /// Get a required valid value, refer to `Validator::try_get_required`.
+    pub fn try_get_required(&self, name: &str) -> Result<FieldValue, GetError> {
+        self.view().required(name)
+    }
+
+    /// Get an optional valid value, refer to `Validator::try_get_optional`.
+    pub fn try_get_optional(&self, name: &str) -> Result<Option<FieldValue>, GetError> {
+        self.view().optional(name)
+    }
+
+    /// Get required multiple valid values, refer to `Validator::try_get_required_multiple`.
+    pub fn try_get_required_multiple(&self, name: &str) -> Result<Vec<FieldValue>, GetError> {
+        self.view().required_multiple(name)
+    }
+
+    /// Get optional multiple valid values, refer to `Validator::try_get_optional_multiple`.
+    pub fn try_get_optional_multiple(&self, name: &str) -> Result<Option<Vec<FieldValue>>, GetError> {
+        self.view().optional_multiple(name)
+    }
+
+    /// Get a required str value, refer to `Validator::try_get_str`.
+    pub fn try_get_str(&self, name: &str) -> Result<String, GetError> {
+        self.view().str(name)
+    }
+
+    /// Get a required i64 value, refer to `Validator::try_get_i64`.
+    pub fn try_get_i64(&self, name: &str) -> Result<i64, GetError> {
+        self.view().i64(name)
+    }
+
+    /// Get a required f64 value, refer to `Validator::try_get_f64`.
+    pub fn try_get_f64(&self, name: &str) -> Result<f64, GetError> {
+        self.view().f64(name)
+    }
+
+    /// Get a required decimal value, refer to `Validator::try_get_decimal`.
+    pub fn try_get_decimal(&self, name: &str) -> Result<DecimalValue, GetError> {
+        self.view().decimal(name)
+    }
+
+    /// Get a required bool value, refer to `Validator::try_get_bool`.
+    pub fn try_get_bool(&self, name: &str) -> Result<bool, GetError> {
+        self.view().bool(name)
+    }
+
+    /// Get a required date, refer to `Validator::try_get_date`.
+    pub fn try_get_date(&self, name: &str) -> Result<NaiveDate, GetError> {
+        self.view().date(name)
+    }
+
+    /// Get a required time of day, refer to `Validator::try_get_time`.
+    pub fn try_get_time(&self, name: &str) -> Result<NaiveTime, GetError> {
+        self.view().time(name)
+    }
+
+    /// Get a required date and time, refer to `Validator::try_get_datetime`.
+    pub fn try_get_datetime(&self, name: &str) -> Result<chrono::DateTime<FixedOffset>, GetError> {
+        self.view().datetime(name)
+    }
+
+    /// Get a required uploaded file, refer to `Validator::try_get_file`.
+    pub fn try_get_file(&self, name: &str) -> Result<UploadedFile, GetError> {
+        self.view().file(name)
+    }
+
+    /// Get an error message by field name, refer to `Validator::try_get_error`.
+    pub fn try_get_error(&self, name: &str) -> Result<String, GetError> {
+        self.view().error(name)
+    }
+
+    /// Get all error messages of a field, refer to `Validator::try_get_field_errors`.
+    pub fn try_get_field_errors(&self, name: &str) -> Result<Vec<InvalidMessage>, GetError> {
+        self.view().field_errors(name)
+    }
+
+    /// Get an arbitrary error message, None if there is no invalid field.
+    pub fn try_get_some_error(&self) -> Option<String> {
+        self.view().some_error()
+    }
+
+    fn view(&self) -> View<'_> {
+        View {
+            valid_data: &self.valid_data,
+            invalid_messages: &self.invalid_messages,
+            field_errors: &self.field_errors,
+        }
+    }
+}
+
+/// The error returned by the `try_get_*` getters.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GetError {
+    /// No checker is known by this field name, maybe it is misspelled.
+    Unknown(String),
+    /// The field is invalid, so there is no valid value.
+    Invalid(String),
+    /// The field is valid but missing, eg. an optional field.
+    Absent(String),
+    /// The field is valid, so there is no error message.
+    Valid(String),
+    /// The value is not of the expected `FieldValue` variant.
+    Mismatch(String),
+}
+
+impl fmt::Display for GetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            GetError::Unknown(ref name) => write!(f, "field `{}` is unknown", name),
+            GetError::Invalid(ref name) => write!(f, "field `{}` is invalid", name),
+            GetError::Absent(ref name) => write!(f, "field `{}` is absent", name),
+            GetError::Valid(ref name) => write!(f, "field `{}` is valid", name),
+            GetError::Mismatch(ref name) => write!(f, "field `{}` is not of the expected type", name),
+        }
+    }
+}
+
+impl std::error::Error for GetError {}
+
+/// Borrowed valid data and invalid messages, shared by the getters of
+/// `Validator` and `ValidationResult`.
+struct View<'a> {
+    valid_data: &'a ValidData,
+    invalid_messages: &'a InvalidMessages,
+    field_errors: &'a FieldErrors,
+}
+
+impl<'a> View<'a> {
+    fn values(&self, name: &str) -> Result<&'a Option<Vec<FieldValue>>, GetError> {
+        match self.valid_data.get(name) {
+            Some(values) => Ok(values),
+            None if self.invalid_messages.contains_key(name) => Err(GetError::Invalid(name.to_string())),
+            None => Err(GetError::Unknown(name.to_string())),
+        }
+    }
+
+    fn required(&self, name: &str) -> Result<FieldValue, GetError> {
+        match *self.values(name)? {
+            Some(ref values) if !values.is_empty() => Ok(values[0].clone()),
+            _ => Err(GetError::Absent(name.to_string())),
+        }
+    }
+
+    fn optional(&self, name: &str) -> Result<Option<FieldValue>, GetError> {
+        Ok(self.values(name)?.as_ref().and_then(|values| values.first().cloned()))
+    }
+
+    fn required_multiple(&self, name: &str) -> Result<Vec<FieldValue>, GetError> {
+        match *self.values(name)? {
+            Some(ref values) => Ok(values.clone()),
+            None => Err(GetError::Absent(name.to_string())),
+        }
+    }
+
+    fn optional_multiple(&self, name: &str) -> Result<Option<Vec<FieldValue>>, GetError> {
+        Ok(self.values(name)?.clone())
+    }
+
+    fn str(&self, name: &str) -> Result<String, GetError> {
+        self.required(name)?.as_str().ok_or_else(|| GetError::Mismatch(name.to_string()))
+    }
+
+    fn i64(&self, name: &str) -> Result<i64, GetError> {
+        self.required(name)?.as_i64().ok_or_else(|| GetError::Mismatch(name.to_string()))
+    }
+
+    fn f64(&self, name: &str) -> Result<f64, GetError> {
+        self.required(name)?.as_f64().ok_or_else(|| GetError::Mismatch(name.to_string()))
+    }
+
+    fn decimal(&self, name: &str) -> Result<DecimalValue, GetError> {
+        self.required(name)?.as_decimal().ok_or_else(|| GetError::Mismatch(name.to_string()))
+    }
+
+    fn bool(&self, name: &str) -> Result<bool, GetError> {
+        self.required(name)?.as_bool().ok_or_else(|| GetError::Mismatch(name.to_string()))
+    }
+
+    fn date(&self, name: &str) -> Result<NaiveDate, GetError> {
+        self.required(name)?.as_date().ok_or_else(|| GetError::Mismatch(name.to_string()))
+    }
+
+    fn time(&self, name: &str) -> Result<NaiveTime, GetError> {
+        self.required(name)?.as_time().ok_or_else(|| GetError::Mismatch(name.to_string()))
+    }
+
+    fn datetime(&self, name: &str) -> Result<chrono::DateTime<FixedOffset>, GetError> {
+        self.required(name)?.as_datetime().ok_or_else(|| GetError::Mismatch(name.to_string()))
+    }
+
+    fn file(&self, name: &str) -> Result<UploadedFile, GetError> {
+        self.required(name)?.as_file().ok_or_else(|| GetError::Mismatch(name.to_string()))
+    }
+
+    fn field_errors(&self, name: &str) -> Result<Vec<InvalidMessage>, GetError> {
+        match self.field_errors.get(name) {
+            Some(messages) => Ok(messages.clone()),
+            None if self.valid_data.contains_key(name) => Err(GetError::Valid(name.to_string())),
+            None => Err(GetError::Unknown(name.to_string())),
+        }
+    }
+
+    fn error(&self, name: &str) -> Result<String, GetError> {
+        match self.invalid_messages.get(name) {
+            Some(message) => Ok(message.clone()),
+            None if self.valid_data.contains_key(name) => Err(GetError::Valid(name.to_string())),
+            None => Err(GetError::Unknown(name.to_string())),
+        }
+    }
+
+    fn errors(&self) -> Vec<String> {
+        self.field_errors.values().flat_map(|v| v.iter().map(|m| m.text.clone())).collect()
+    }
+
+    fn some_error(&self) -> Option<String> {
+        self.invalid_messages.values().next().cloned()
+    }
+}
+
+fn or_panic<V>(result: Result<V, GetError>) -> V {
+    result.unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// This enum is used to mark a type of a `Message`
+///
+/// New kinds are added along with new rules and field types, so a
+/// `MessageRenderer` matching on it needs a catch-all arm.
+#[non_exhaustive]
+pub enum MessageKind {
+    /// Greater than maximum value, eg. for an int value.
+    Max,
+    /// Less than mininum value, eg. for an int value.
+    Min,
+    /// Longer than maximum lenghth, eg. for a string value.
+    MaxLen,
+    /// Shorter than minimum length, eg. for a string value.
+    MinLen,
+    /// Value required, but missing.
+    Blank,
+    /// Value not match some format.
+    Format,
+    /// Not before the date or time given, refer to `Rule::Before`.
+    Before,
+    /// Not after the date or time given, refer to `Rule::After`.
+    After,
+    /// Later than now, refer to `Rule::NotInFuture`.
+    Future,
+    /// Earlier than now, refer to `Rule::NotInPast`.
+    Past,
+    /// Too far from now, refer to `Rule::Within`. The rule values are the
+    /// count and the unit, one of `days`, `hours`, `minutes` and `seconds`.
+    Within,
+    /// Younger than the minimum age, refer to `Rule::MinAge`.
+    MinAge,
+    /// Not one of the allowed values, refer to `Choice` and `Rule::OneOf`.
+    /// The rule values are the allowed values.
+    OneOf,
+    /// One of the forbidden values, refer to `Rule::NoneOf`. The rule values
+    /// are the forbidden values.
+    NoneOf,
+    /// Not equal to another field, refer to `Rule::EqualsField`. The rule
+    /// values are the title and the value of the other field, so are those
+    /// of the kinds below.
+    EqualsField,
+    /// Equal to another field, refer to `Rule::NotEqualsField`.
+    NotEqualsField,
+    /// Not less than another field, refer to `Rule::LessThanField`.
+    LessThanField,
+    /// Greater than another field, refer to `Rule::LessOrEqualField`.
+    LessOrEqualField,
+    /// Not greater than another field, refer to `Rule::GreaterThanField`.
+    GreaterThanField,
+    /// Less than another field, refer to `Rule::GreaterOrEqualField`.
+    GreaterOrEqualField,
+    /// Present but not allowed to be, refer to `CheckerOption::ExcludedIf`.
+    Excluded,
+    /// Fewer items than the minimum, refer to `Rule::MinItems`.
+    MinItems,
+    /// More items than the maximum, refer to `Rule::MaxItems`.
+    MaxItems,
+    /// A duplicate item, refer to `Rule::UniqueItems`. The rule value is the
+    /// duplicate.
+    UniqueItems,
+    /// An item out of order, refer to `Rule::SortedItems`.
+    SortedItems,
+    /// A rule met which must not be, refer to `Rule::Not`.
+    Not,
+    /// None of the rules met, refer to `Rule::AnyOf`. The causes are the
+    /// messages of the rules.
+    AnyOf,
+    /// Some of the rules not met, refer to `Rule::AllOf`. The causes are the
+    /// messages of those rules.
+    AllOf,
+}
+
+/// A general message wrapper
+pub enum Message {
+    /// A customized message, just show any message given.
+    Any(String),
+    /// A kind of message predefined.
+    Some(SomeMessage),
+}
+
+/// A specific message
+pub struct SomeMessage {
+    /// Refer to `MessageKind`.
+    pub kind: MessageKind,
+    /// The field name.
+    pub name: String,
+    /// The field title.
+    pub title: String,
+    /// The field raw value, None if missing.
+    pub value: Option<String>,
+    /// rule related values, such as max and min, as strings.
+    pub rule_values: Vec<String>,
+    /// The index of the offending value for a multiple field, None otherwise.
+    pub index: Option<usize>,
+    /// The messages this one is made of, eg. those of the rules combined by
+    /// `Rule::AnyOf`, empty otherwise. Render them with
+    /// `MessageRenderer::render_cause`.
+    pub causes: Vec<Message>,
+}
+
+impl Message {
+    /// Construct a kind of message.
+    pub fn some(kind: MessageKind, name: &str, title: &str, value: Option<String>, rule_values: Vec<String>) -> Message {
+        Message::Some(SomeMessage {
+            kind,
+            name: name.to_string(),
+            title: title.to_string(),
+            value,
+            rule_values,
+            index: None,
+            causes: Vec::new(),
+        })
+    }
+
+    /// Attach the messages this one is made of, refer to `SomeMessage::causes`.
+    ///
+    /// A customized message has no room for causes, and is left as is.
+    pub fn caused_by(self, causes: Vec<Message>) -> Message {
+        match self {
+            Message::Some(mut m) => {
+                m.causes = causes;
+                Message::Some(m)
+            },
+            any => any,
+        }
+    }
+
+    /// Mark the message as being about the value at `index` of a multiple field.
+    ///
+    /// A customized message has no room for an index, and is left as is.
+    pub fn at(self, index: usize) -> Message {
+        match self {
+            Message::Some(mut m) => {
+                m.index = Some(index);
+                Message::Some(m)
+            },
+            any => any,
+        }
+    }
+
+    /// The index of the offending value, refer to `Message::at`.
+    pub fn index(&self) -> Option<usize> {
+        match *self {
+            Message::Some(ref m) => m.index,
+            Message::Any(_) => None,
+        }
+    }
+
+    /// Construct a customized message.
+    pub fn any(message: &str) -> Message {
+        Message::Any(message.to_string())
+    }
+}
+
+/// If you want to control how the message is displayed, implement this trait.
+///
+/// The default implementation is in simple Chinese.
+pub trait MessageRenderer {
+    fn render_message(&self, m: SomeMessage) -> String {
+        match m.kind {
+            MessageKind::Max => format!("{title}不能大于{rule}", title=m.title, rule=m.rule_values[0]),
+            MessageKind::Min => format!("{title}不能小于{rule}", title=m.title, rule=m.rule_values[0]),
+            MessageKind::MaxLen => format!("{title}长度不能大于{rule}", title=m.title, rule=m.rule_values[0]),
+            MessageKind::MinLen => format!("{title}长度不能小于{rule}", title=m.title, rule=m.rule_values[0]),
+            MessageKind::Blank => format!("{title}不能为空", title=m.title),
+            MessageKind::Format => format!("{title}格式不正确", title=m.title),
+            MessageKind::Before => format!("{title}必须早于{rule}", title=m.title, rule=m.rule_values[0]),
+            MessageKind::After => format!("{title}必须晚于{rule}", title=m.title, rule=m.rule_values[0]),
+            MessageKind::Future => format!("{title}不能晚于现在", title=m.title),
+            MessageKind::Past => format!("{title}不能早于现在", title=m.title),
+            MessageKind::Within => {
+                let unit = match m.rule_values[1].as_str() {
+                    "days" => "天",
+                    "hours" => "小时",
+                    "minutes" => "分钟",
+                    _ => "秒",
+                };
+                format!("{title}必须在距今{count}{unit}以内", title=m.title, count=m.rule_values[0], unit=unit)
+            },
+            MessageKind::MinAge => format!("{title}须满{rule}周岁", title=m.title, rule=m.rule_values[0]),
+            MessageKind::OneOf => format!("{title}必须是{rule}之一", title=m.title, rule=m.rule_values.join("、")),
+            MessageKind::NoneOf => format!("{title}不能是{rule}", title=m.title, rule=m.rule_values.join("、")),
+            MessageKind::EqualsField => format!("{title}必须与{other}一致", title=m.title, other=m.rule_values[0]),
+            MessageKind::NotEqualsField => format!("{title}不能与{other}相同", title=m.title, other=m.rule_values[0]),
+            MessageKind::LessThanField => format!("{title}必须小于{other}", title=m.title, other=m.rule_values[0]),
+            MessageKind::LessOrEqualField => format!("{title}不能大于{other}", title=m.title, other=m.rule_values[0]),
+            MessageKind::GreaterThanField => format!("{title}必须大于{other}", title=m.title, other=m.rule_values[0]),
+            MessageKind::GreaterOrEqualField => format!("{title}不能小于{other}", title=m.title, other=m.rule_values[0]),
+            MessageKind::Excluded => format!("{title}必须为空", title=m.title),
+            MessageKind::MinItems => format!("{title}不能少于{rule}项", title=m.title, rule=m.rule_values[0]),
+            MessageKind::MaxItems => format!("{title}不能多于{rule}项", title=m.title, rule=m.rule_values[0]),
+            MessageKind::UniqueItems => format!("{title}不能重复", title=m.title),
+            MessageKind::SortedItems => format!("{title}必须按顺序排列", title=m.title),
+            MessageKind::Not => format!("{title}不符合要求", title=m.title),
+            MessageKind::AnyOf => {
+                let causes: Vec<String> = m.causes.into_iter().map(|cause| self.render_cause(cause)).collect();
+                format!("{title}须满足以下条件之一：{causes}", title=m.title, causes=causes.join("；"))
+            },
+            MessageKind::AllOf => {
+                let causes: Vec<String> = m.causes.into_iter().map(|cause| self.render_cause(cause)).collect();
+                causes.join("；")
+            },
+        }
+    }
+
+    /// Render a cause of a message, refer to `SomeMessage::causes`.
+    fn render_cause(&self, cause: Message) -> String {
+        match cause {
+            Message::Any(s) => s,
+            Message::Some(m) => self.render_message(m),
+        }
+    }
+}
+
+trait Renderable {
+    fn render(&self, m:Message) -> String;
+}
+
+impl<T:MessageRenderer> Renderable for T {
+    fn render(&self, m: Message) -> String {
+        match m {
+            Message::Any(s) => s,
+            Message::Some(km) => self.render_message(km),
+        }
+    }
+}
+
+impl MessageRenderer for () {
+}
 
 /// Option you can set to a checker.
 ///
@@ -473,7 +1547,7 @@ impl MessageRenderer for () {
 ///     .check(Checker::new("tags", "标签", Str)
 ///            .set(CheckerOption::Optional(true))
 ///            .set(CheckerOption::Multiple(true))
-///            .meet(Rule::Min(1)));
+///            .meet(Rule::MaxItems(3)));
 /// validator.validate(&params);
 /// assert!(validator.is_valid());
 /// ```
@@ -482,6 +1556,225 @@ pub enum CheckerOption {
     Optional(bool),
     /// True means this field consists of multiple values, default false(single value).
     Multiple(bool),
+    /// True means all failing rules of all values are reported, default false(stop at
+    /// the first failing rule).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, CheckerOption, Rule, Str};
+    /// let mut params = std::collections::HashMap::new();
+    /// params.insert("tags".to_string(), vec!["red".to_string(), "b".to_string(), "c".to_string()]);
+    ///
+    /// let mut validator = Validator::new();
+    /// validator
+    ///     .check(Checker::new("tags", "标签", Str)
+    ///            .set(CheckerOption::Multiple(true))
+    ///            .set(CheckerOption::CollectAll(true))
+    ///            .meet(Rule::Min(2)));
+    /// validator.validate(&params);
+    /// let errors = validator.get_field_errors("tags");
+    /// assert_eq!(errors.iter().map(|e| e.index.unwrap()).collect::<Vec<_>>(), vec![1, 2]);
+    /// ```
+    CollectAll(bool),
+    /// How `Rule::Max` and `Rule::Min` count the length of a str value,
+    /// default `LengthMode::Chars`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, CheckerOption, LengthMode, Rule, Str};
+    /// let mut params = std::collections::HashMap::new();
+    /// params.insert("name".to_string(), vec!["张三丰".to_string()]);
+    /// params.insert("name_db".to_string(), vec!["张三丰".to_string()]);
+    ///
+    /// let mut validator = Validator::new();
+    /// validator
+    ///     .check(Checker::new("name", "姓名", Str)
+    ///            .meet(Rule::Max(3)))
+    ///     .check(Checker::new("name_db", "存储姓名", Str)
+    ///            .set(CheckerOption::Length(LengthMode::Bytes))
+    ///            .meet(Rule::Max(3)));
+    /// validator.validate(&params);
+    /// assert_eq!(validator.get_optional("name").unwrap().as_str().unwrap(), "张三丰");
+    /// assert_eq!(validator.get_error("name_db"), "存储姓名长度不能大于3");
+    /// ```
+    Length(LengthMode),
+    /// The raw value of this field when it is missing, which is then valid
+    /// instead of missing. It still goes through the filters, the field
+    /// type and the rules, and an invalid one makes `Validator::check` panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, CheckerOption, Rule, I64};
+    /// let mut validator = Validator::new();
+    /// validator
+    ///     .check(Checker::new("page", "页码", I64)
+    ///            .set(CheckerOption::Default("1".to_string()))
+    ///            .meet(Rule::Min(1)));
+    ///
+    /// let params = std::collections::HashMap::new();
+    /// assert_eq!(validator.evaluate(&params).get_required("page").as_i64(), Some(1));
+    /// ```
+    Default(String),
+    /// The value of this field when it is missing, refer to
+    /// `CheckerOption::Default`. It goes through the rules only.
+    DefaultValue(FieldValue),
+    /// How an empty or whitespace-only str value of this field is treated,
+    /// after the filters, default the policy of the validator, refer to
+    /// `Validator::empty_policy`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, CheckerOption, EmptyPolicy, Str};
+    /// let mut params = std::collections::HashMap::new();
+    /// params.insert("name".to_string(), vec!["".to_string()]);
+    /// params.insert("nickname".to_string(), vec!["  ".to_string()]);
+    ///
+    /// let mut validator = Validator::new();
+    /// validator
+    ///     .check(Checker::new("name", "姓名", Str)
+    ///            .set(CheckerOption::Empty(EmptyPolicy::Missing)))
+    ///     .check(Checker::new("nickname", "昵称", Str)
+    ///            .set(CheckerOption::Optional(true))
+    ///            .set(CheckerOption::Empty(EmptyPolicy::Missing)));
+    /// let result = validator.evaluate(&params);
+    /// assert_eq!(result.get_error("name"), "姓名不能为空");
+    /// assert_eq!(result.get_optional("nickname"), None);
+    /// ```
+    Empty(EmptyPolicy),
+    /// This field is required if the field named has the value given, and
+    /// optional otherwise.
+    ///
+    /// The conditions, this one and those below, look at the values of the
+    /// other field, in the same `Group` as this one if any, cleaned by its
+    /// filters, where an empty value is not present. A field with any `Required...` condition
+    /// is required if one of them holds and optional otherwise, whatever
+    /// `CheckerOption::Optional` says. A missing required field is blank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, CheckerOption, Str};
+    /// let mut validator = Validator::new();
+    /// validator
+    ///     .check(Checker::new("account_type", "账户类型", Str))
+    ///     .check(Checker::new("company", "公司名称", Str)
+    ///            .set(CheckerOption::RequiredIf("account_type".to_string(), "business".to_string())));
+    ///
+    /// let mut params = std::collections::HashMap::new();
+    /// params.insert("account_type".to_string(), vec!["personal".to_string()]);
+    /// assert!(validator.evaluate(&params).is_valid());
+    ///
+    /// params.insert("account_type".to_string(), vec!["business".to_string()]);
+    /// assert_eq!(validator.evaluate(&params).get_error("company"), "公司名称不能为空");
+    /// ```
+    RequiredIf(String, String),
+    /// This field is required unless the field named has the value given,
+    /// refer to `CheckerOption::RequiredIf`.
+    RequiredUnless(String, String),
+    /// This field is required if the field named is present, refer to
+    /// `CheckerOption::RequiredIf`.
+    RequiredWith(String),
+    /// This field is required if the field named is not present, refer to
+    /// `CheckerOption::RequiredIf`.
+    RequiredWithout(String),
+    /// This field must not be present if the field named has the value
+    /// given, refer to `CheckerOption::RequiredIf`. It is missing then.
+    ExcludedIf(String, String),
+}
+
+/// A condition on other fields, refer to `CheckerOption::RequiredIf`.
+enum Condition {
+    RequiredIf(String, String),
+    RequiredUnless(String, String),
+    RequiredWith(String),
+    RequiredWithout(String),
+    ExcludedIf(String, String),
+}
+
+impl Condition {
+    fn holds(&self, params: &dyn Source, siblings: &[Box<dyn Checkable>]) -> bool {
+        match *self {
+            Condition::RequiredIf(ref name, ref value) | Condition::ExcludedIf(ref name, ref value) => has_value(params, siblings, name, value),
+            Condition::RequiredUnless(ref name, ref value) => !has_value(params, siblings, name, value),
+            Condition::RequiredWith(ref name) => is_present(params, siblings, name),
+            Condition::RequiredWithout(ref name) => !is_present(params, siblings, name),
+        }
+    }
+}
+
+fn is_present(params: &dyn Source, siblings: &[Box<dyn Checkable>], name: &str) -> bool {
+    any_value(params, siblings, name, |value| !value.is_empty())
+}
+
+fn has_value(params: &dyn Source, siblings: &[Box<dyn Checkable>], name: &str, value: &str) -> bool {
+    any_value(params, siblings, name, |v| v.to_string() == value)
+}
+
+/// Tell if any value of a field, cleaned by the filters of its checker among
+/// siblings if any, meets the predicate.
+fn any_value<F: Fn(&RawValue) -> bool>(params: &dyn Source, siblings: &[Box<dyn Checkable>], name: &str, predicate: F) -> bool {
+    let sibling = siblings.iter().find(|checkable| checkable.get_name() == name);
+    params.get(name).is_some_and(|values| values.iter().any(|value| match (*value, sibling) {
+        (RawValue::Str(s), Some(sibling)) => predicate(&RawValue::Str(&sibling.filter_value(s))),
+        _ => predicate(value),
+    }))
+}
+
+/// How an empty or whitespace-only str value is treated, refer to
+/// `CheckerOption::Empty`.
+///
+/// Browsers submit a text input left empty as `name=`, which is a value
+/// unless told otherwise.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EmptyPolicy {
+    /// An empty value is a value like any other.
+    #[default]
+    Value,
+    /// An empty value is taken as missing, so a required field is blank and
+    /// an optional one is missing or takes its default value. Other values
+    /// of a multiple field are kept.
+    Missing,
+    /// An empty value is blank, even for an optional field.
+    Reject,
+}
+
+/// A default value, refer to `CheckerOption::Default`.
+enum DefaultValue {
+    Raw(String),
+    Value(FieldValue),
+}
+
+/// How the length of a str value is counted, refer to `CheckerOption::Length`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LengthMode {
+    /// UTF-8 bytes, eg. for a database column limited in bytes. `张三` is 6.
+    Bytes,
+    /// Unicode scalar values. `张三` is 2, so is `é` written as `e` and a
+    /// combining accent.
+    #[default]
+    Chars,
+    /// Grapheme clusters, the characters a user sees. `é` is 1 however it
+    /// is written, so is a family emoji.
+    Graphemes,
+    /// Display width in a monospace font, where a CJK character is 2. `张三`
+    /// is 4.
+    Width,
+}
+
+impl LengthMode {
+    /// The length of `s` counted in this mode.
+    pub fn count(self, s: &str) -> usize {
+        match self {
+            LengthMode::Bytes => s.len(),
+            LengthMode::Chars => s.chars().count(),
+            LengthMode::Graphemes => s.graphemes(true).count(),
+            LengthMode::Width => s.width(),
+        }
+    }
 }
 
 /// The checker for a field.
@@ -489,53 +1782,99 @@ pub struct Checker<T: FieldType> {
     field_name: String,
     field_title: String,
     field_type: T,
+    filters: Vec<Box<dyn Filter>>,
     rules: Vec<Rule>,
     optional: bool,
     multiple: bool,
+    collect_all: bool,
+    length: LengthMode,
+    default: Option<DefaultValue>,
+    empty_policy: Option<EmptyPolicy>,
+    inherited_empty_policy: EmptyPolicy,
+    conditions: Vec<Condition>,
 }
 
 impl<T: FieldType> Checkable for Checker<T> {
-    fn check(&self, params: &HashMap<String, Vec<String>>) -> Result<Option<Vec<FieldValue>>, Message> {
-        let values = params.get(&self.field_name);
-
-        if values.is_none() {
-            if !self.optional {
-                return Err(Message::some(MessageKind::Blank,
-                                        &self.field_name,
-                                        &self.field_title,
-                                        None, Vec::new()));
-            }
+    fn check(&self, params: &dyn Source) -> CheckResult {
+        self.check_with_siblings(params, &[])
+    }
 
-            return Ok(None)
+    fn check_with_siblings(&self, params: &dyn Source, siblings: &[Box<dyn Checkable>]) -> CheckResult {
+        let excluded = self.conditions.iter()
+            .any(|condition| matches!(*condition, Condition::ExcludedIf(..)) && condition.holds(params, siblings));
+        if excluded {
+            if let Some(value) = params.get(&self.field_name).and_then(|values| values.into_iter().find(|value| !value.is_empty())) {
+                return Err(vec![Message::some(MessageKind::Excluded,
+                                              &self.field_name,
+                                              &self.field_title,
+                                              Some(value.to_string()), Vec::new())]);
+            }
+            return Ok(None);
+        }
+        let optional = self.is_optional(params, siblings);
+
+        let values = match params.get(&self.field_name) {
+            Some(values) => values,
+            None => return self.check_missing(optional),
+        };
+        let filtered: Vec<Option<String>> = values.iter().map(|value| match *value {
+            RawValue::Str(s) if !self.filters.is_empty() => Some(self.clean(s)),
+            _ => None,
+        }).collect();
+        // Each value with its index as submitted, which is kept in messages
+        // even if empty values are dropped.
+        let mut values: Vec<(usize, RawValue)> = values.iter().zip(&filtered).map(|(&value, filtered)| match *filtered {
+            Some(ref s) => RawValue::Str(s),
+            None => value,
+        }).enumerate().collect();
+        if !self.multiple {
+            values.truncate(1);
         }
 
-        let values = values.unwrap();
+        match self.empty_policy.unwrap_or(self.inherited_empty_policy) {
+            EmptyPolicy::Value => {},
+            EmptyPolicy::Missing => {
+                if !values.is_empty() && values.iter().all(|(_, value)| value.is_empty()) {
+                    return self.check_missing(optional);
+                }
+                values.retain(|(_, value)| !value.is_empty());
+            },
+            EmptyPolicy::Reject => {
+                if let Some(&(i, value)) = values.iter().find(|(_, value)| value.is_empty()) {
+                    let message = Message::some(MessageKind::Blank,
+                                                &self.field_name,
+                                                &self.field_title,
+                                                Some(value.to_string()), Vec::new());
+                    return Err(vec![if self.multiple { message.at(i) } else { message }]);
+                }
+            },
+        }
 
         let mut valid_values = Vec::new();
 
         if self.multiple {
-            for value in values {
+            let mut messages = Vec::new();
+            for &(i, ref value) in &values {
                 match self.check_value(value) {
                     Ok(v) => valid_values.push(v),
-                    Err(msg) => { return Err(msg); }
+                    Err(msgs) => {
+                        messages.extend(msgs.into_iter().map(|m| m.at(i)));
+                        if !self.collect_all {
+                            break;
+                        }
+                    }
                 }
             }
+            if !messages.is_empty() {
+                return Err(messages);
+            }
+            self.meet_item_rules(&valid_values)?;
         } else {
-            if values.len() < 1 {
-                if !self.optional {
-                    return Err(Message::some(MessageKind::Blank,
-                                            &self.field_name,
-                                            &self.field_title,
-                                            None, Vec::new()));
-                }
-
-                return Ok(None)
+            if values.is_empty() {
+                return self.check_missing(optional);
             }
 
-            match self.check_value(&values[0]) {
-                Ok(v) => valid_values.push(v),
-                Err(msg) => { return Err(msg); }
-            }
+            valid_values.push(self.check_value(&values[0].1)?);
         }
 
         Ok(Some(valid_values))
@@ -546,6 +1885,54 @@ impl<T: FieldType> Checkable for Checker<T> {
         self.field_name.clone()
     }
 
+    fn check_default(&self) -> Result<(), Message> {
+        match self.check_default_values() {
+            Some(Err(mut messages)) => Err(messages.remove(0)),
+            _ => Ok(()),
+        }
+    }
+
+    fn inherit_empty_policy(&mut self, policy: EmptyPolicy) {
+        self.inherited_empty_policy = policy;
+    }
+
+    fn get_title(&self) -> String {
+        self.field_title.clone()
+    }
+
+    fn filter_value(&self, value: &str) -> String {
+        self.clean(value)
+    }
+
+    fn check_cross_fields(&self, siblings: &[Box<dyn Checkable>], path: &str, data: &ValidData, out: &mut Vec<(String, Message)>) {
+        let full_path = nested::join_path(path, &self.field_name);
+        let values = match data.get(&full_path) {
+            Some(Some(values)) => values,
+            _ => return,
+        };
+        for rule in &self.rules {
+            let name = match rule.other_field() {
+                Some(name) => name,
+                None => continue,
+            };
+            let other = match data.get(&nested::join_path(path, name)) {
+                Some(Some(others)) if !others.is_empty() => &others[0],
+                _ => continue,
+            };
+            let other_title = siblings.iter()
+                .find(|sibling| sibling.get_name() == name)
+                .map_or_else(|| name.to_string(), |sibling| sibling.get_title());
+            for (i, value) in values.iter().enumerate() {
+                if let Err(message) = match_field(rule, value, &other_title, other, &self.field_name, &self.field_title) {
+                    out.push((full_path.clone(), if self.multiple { message.at(i) } else { message }));
+                    if !self.collect_all {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
 }
 
 impl<T: FieldType> Checker<T> {
@@ -560,27 +1947,159 @@ impl<T: FieldType> Checker<T> {
         Checker {
             field_name: field_name.to_string(),
             field_title: field_title.to_string(),
-            field_type: field_type,
+            field_type,
+            filters: Vec::new(),
             rules: Vec::new(),
             optional: false,
             multiple: false,
+            collect_all: false,
+            length: LengthMode::default(),
+            default: None,
+            empty_policy: None,
+            inherited_empty_policy: EmptyPolicy::default(),
+            conditions: Vec::new(),
+        }
+    }
+
+    fn clean(&self, value: &str) -> String {
+        self.filters.iter().fold(value.to_string(), |value, filter| filter.filter(&value))
+    }
+
+    fn check_value(&self, value: &RawValue) -> Result<FieldValue, Vec<Message>> {
+        let field_value = self.field_type.from_raw(&self.field_name, &self.field_title, value)
+            .map_err(|msg| vec![msg])?;
+        self.meet_rules(field_value, &value.to_string())
+    }
+
+    fn check_default_values(&self) -> Option<Result<Vec<FieldValue>, Vec<Message>>> {
+        let value = match self.default {
+            Some(DefaultValue::Raw(ref raw)) => self.check_value(&RawValue::Str(&self.clean(raw))),
+            Some(DefaultValue::Value(ref field_value)) => self.meet_rules(field_value.clone(), &field_value.to_string()),
+            None => return None,
+        };
+        let values = match value {
+            Ok(value) => vec![value],
+            Err(messages) => return Some(Err(messages)),
+        };
+        if self.multiple {
+            if let Err(messages) = self.meet_item_rules(&values) {
+                return Some(Err(messages));
+            }
+        }
+        Some(Ok(values))
+    }
+
+    /// Whether this field is optional, given the conditions on other fields
+    /// if any, refer to `CheckerOption::RequiredIf`.
+    fn is_optional(&self, params: &dyn Source, siblings: &[Box<dyn Checkable>]) -> bool {
+        let mut required = self.conditions.iter()
+            .filter(|condition| !matches!(**condition, Condition::ExcludedIf(..)))
+            .peekable();
+        if required.peek().is_none() {
+            return self.optional;
+        }
+        !required.any(|condition| condition.holds(params, siblings))
+    }
+
+    /// A missing field takes its default value if any. Otherwise, it is
+    /// blank unless optional, or unless its field type gives a value for it,
+    /// refer to `FieldType::missing`.
+    fn check_missing(&self, optional: bool) -> CheckResult {
+        if let Some(values) = self.check_default_values() {
+            return Ok(Some(values?));
+        }
+        if optional {
+            return Ok(None);
+        }
+        match self.field_type.missing() {
+            Some(_) if self.multiple => {
+                self.meet_item_rules(&[])?;
+                Ok(Some(Vec::new()))
+            },
+            Some(field_value) => {
+                let value = field_value.to_string();
+                Ok(Some(vec![self.meet_rules(field_value, &value)?]))
+            },
+            None => Err(vec![Message::some(MessageKind::Blank,
+                                           &self.field_name,
+                                           &self.field_title,
+                                           None, Vec::new())]),
+        }
+    }
+
+    fn meet_item_rules(&self, values: &[FieldValue]) -> Result<(), Vec<Message>> {
+        let mut messages = Vec::new();
+        for rule in &self.rules {
+            if let Err(msg) = match_items(rule, values, &self.field_name, &self.field_title) {
+                messages.push(msg);
+                if !self.collect_all {
+                    break;
+                }
+            }
+        }
+        if !messages.is_empty() {
+            return Err(messages);
         }
+        Ok(())
     }
 
-    fn check_value(&self, value: &str) -> Result<FieldValue, Message> {
-        let field_value = try!(self.field_type.from_str(&self.field_name, &self.field_title, value));
+    fn meet_rules(&self, field_value: FieldValue, value: &str) -> Result<FieldValue, Vec<Message>> {
+        let mut messages = Vec::new();
         for rule in &self.rules {
-            if let Err(msg) = field_value.match_rule(&self.field_name, &self.field_title, value, rule) {
-                return Err(msg);
+            if let Err(msg) = field_value.match_rule(&self.field_name, &self.field_title, value, rule, self.length) {
+                messages.push(msg);
+                if !self.collect_all {
+                    break;
+                }
             }
         }
+        if !messages.is_empty() {
+            return Err(messages);
+        }
         Ok(field_value)
     }
 
+    /// Add a filter to this checker, refer to the `Filter`.
+    ///
+    /// Filters clean a str value in the order added, before the field type
+    /// sees it. The cleaned value is what is checked and kept as valid data.
+    pub fn filter<F: Filter + 'static>(mut self, filter: F) -> Checker<T> {
+        self.filters.push(Box::new(filter));
+        self
+    }
+
     /// Add a rule to this checker, refer to the `Rule`.
-    pub fn meet(mut self, rule: Rule) -> Checker<T> {
+    ///
+    /// # Panics
+    ///
+    /// Panics if a `Format` made from an invalid pattern is in the rule, or if
+    /// a rule met once all the fields are checked or by all the values
+    /// together, like `Rule::EqualsField` or `Rule::MinItems`, is combined by
+    /// `Rule::Not`, `Rule::AnyOf`, `Rule::AllOf` or `Rule::When`.
+    pub fn meet(self, rule: Rule) -> Checker<T> {
+        let field_name = self.field_name.clone();
+        match self.try_meet(rule) {
+            Ok(checker) => checker,
+            Err(e) => panic!("invalid rule for field `{}`: {}", field_name, e),
+        }
+    }
+
+    /// Add a rule to this checker, or tell why it can't be met, refer to
+    /// `meet`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Checker, Rule, RuleError, Str};
+    /// // A pattern from configuration, which may be invalid.
+    /// let pattern = r"l\d(y";
+    /// let result = Checker::new("code", "编码", Str).try_meet(Rule::Format(pattern.into()));
+    /// assert!(matches!(result, Err(RuleError::Format(_))));
+    /// ```
+    pub fn try_meet(mut self, rule: Rule) -> Result<Checker<T>, RuleError> {
+        rule.check_rule()?;
         self.rules.push(rule);
-        self
+        Ok(self)
     }
 
     /// Set an option for this checker, refer to the `CheckerOption`.
@@ -591,7 +2110,37 @@ impl<T: FieldType> Checker<T> {
             },
             CheckerOption::Multiple(multiple) => {
                 self.multiple = multiple;
-            }
+            },
+            CheckerOption::CollectAll(collect_all) => {
+                self.collect_all = collect_all;
+            },
+            CheckerOption::Length(length) => {
+                self.length = length;
+            },
+            CheckerOption::Default(raw) => {
+                self.default = Some(DefaultValue::Raw(raw));
+            },
+            CheckerOption::DefaultValue(field_value) => {
+                self.default = Some(DefaultValue::Value(field_value));
+            },
+            CheckerOption::Empty(policy) => {
+                self.empty_policy = Some(policy);
+            },
+            CheckerOption::RequiredIf(name, value) => {
+                self.conditions.push(Condition::RequiredIf(name, value));
+            },
+            CheckerOption::RequiredUnless(name, value) => {
+                self.conditions.push(Condition::RequiredUnless(name, value));
+            },
+            CheckerOption::RequiredWith(name) => {
+                self.conditions.push(Condition::RequiredWith(name));
+            },
+            CheckerOption::RequiredWithout(name) => {
+                self.conditions.push(Condition::RequiredWithout(name));
+            },
+            CheckerOption::ExcludedIf(name, value) => {
+                self.conditions.push(Condition::ExcludedIf(name, value));
+            },
         }
         self
     }
@@ -602,21 +2151,384 @@ impl<T: FieldType> Checker<T> {
 ///
 /// Note that for diffent `FieldValue`, the same rule might mean diffent.
 /// For example, Max means the maximum length for str value, but means
-/// the maximum value for integer value.
+/// the maximum value for integer value. The length of a str value is
+/// counted in chars unless set by `CheckerOption::Length`.
 pub enum Rule {
     /// Maximum limit.
+    ///
+    /// It is the maximum length of a str value or of a file, and the maximum
+    /// of an `I64`, `F64` or `Decimal` value, compared as numbers.
     Max(i64),
-    /// Mininum limit.
+    /// Mininum limit, refer to `Rule::Max`.
     Min(i64),
-    /// A regex pattern to match against the str representation of `FieldValue`.
-    Format(&'static str),
+    /// Maximum limit for a number, only needed if the limit has a fractional
+    /// part, `Rule::Max` is fine for a whole one.
+    ///
+    /// It applies to `F64` and `I64` values, other values are not limited.
+    MaxF64(f64),
+    /// Mininum limit for a number, refer to `Rule::MaxF64`.
+    MinF64(f64),
+    /// Maximum limit for a `Decimal` value.
+    MaxDecimal(DecimalValue),
+    /// Mininum limit for a `Decimal` value.
+    MinDecimal(DecimalValue),
+    /// Maximum number of digits after the decimal point of an `F64` or a
+    /// `Decimal` value, as submitted, eg. 2 for a price.
+    Scale(u32),
+    /// Maximum number of digits in all of a `Decimal` value, refer to
+    /// `DecimalValue::precision`.
+    Precision(u32),
+    /// A `Date`, `Time` or `DateTime` value must be strictly before the one
+    /// given, which must be of the same kind, eg. a `FieldValue::Date`.
+    /// Other values, or a bound of another kind, are in wrong format, and so
+    /// are other values for the date rules below.
+    Before(FieldValue),
+    /// A `Date`, `Time` or `DateTime` value must be strictly after the one
+    /// given, refer to `Rule::Before`.
+    After(FieldValue),
+    /// A `Date` or `DateTime` value must not be later than now. A date is
+    /// compared with today in the local time zone. A `Time` value is in
+    /// wrong format, and so it is for `Rule::NotInPast`, `Rule::Within` and
+    /// `Rule::MinAge`.
+    NotInFuture,
+    /// A `Date` or `DateTime` value must not be earlier than now, refer to
+    /// `Rule::NotInFuture`.
+    NotInPast,
+    /// A `Date` or `DateTime` value must be within the duration from now,
+    /// either way. Combine it with `Rule::NotInFuture` for "in the last 30
+    /// days".
+    Within(chrono::Duration),
+    /// A `Date` or `DateTime` value, a birthday, must be at least this many
+    /// years ago.
+    MinAge(u32),
+    /// An `F64` value must be finite, neither NaN nor infinity.
+    Finite,
+    /// The str representation of `FieldValue` must be one of the values
+    /// given, compared exactly. Use `Choice` to ignore case or to map values.
+    OneOf(Vec<String>),
+    /// The str representation of `FieldValue` must not be any of the values
+    /// given, compared exactly, eg. reserved user names.
+    NoneOf(Vec<String>),
+    /// The value must equal that of the field named, eg. a password
+    /// confirmation.
+    ///
+    /// The rules referencing other fields are met once all the fields are
+    /// checked, and only if both fields are valid and present. The field
+    /// named is in the same `Group` as this one, if any, and its first value
+    /// is compared. Numbers are compared by value, eg. an `I64` with a
+    /// `Decimal`, and so are two dates or times of the same kind. Values
+    /// which can't be compared, eg. a str and a number, or a `Date` and a
+    /// `DateTime`, are not equal, and don't meet the rules ordering them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, Rule, Str, I64};
+    /// let mut validator = Validator::new();
+    /// validator
+    ///     .check(Checker::new("password", "密码", Str))
+    ///     .check(Checker::new("confirm", "确认密码", Str)
+    ///            .meet(Rule::EqualsField("password".to_string())))
+    ///     .check(Checker::new("min_price", "最低价", I64))
+    ///     .check(Checker::new("max_price", "最高价", I64)
+    ///            .meet(Rule::GreaterOrEqualField("min_price".to_string())));
+    ///
+    /// let mut params = std::collections::HashMap::new();
+    /// params.insert("password".to_string(), vec!["s3cret".to_string()]);
+    /// params.insert("confirm".to_string(), vec!["secret".to_string()]);
+    /// params.insert("min_price".to_string(), vec!["100".to_string()]);
+    /// params.insert("max_price".to_string(), vec!["50".to_string()]);
+    /// let result = validator.evaluate(&params);
+    /// assert_eq!(result.get_error("confirm"), "确认密码必须与密码一致");
+    /// assert_eq!(result.get_error("max_price"), "最高价不能小于最低价");
+    /// ```
+    EqualsField(String),
+    /// The value must not equal that of the field named, refer to
+    /// `Rule::EqualsField`.
+    NotEqualsField(String),
+    /// The value must be less than that of the field named, eg. a start date
+    /// before an end date, refer to `Rule::EqualsField`.
+    LessThanField(String),
+    /// The value must not be greater than that of the field named, refer to
+    /// `Rule::EqualsField`.
+    LessOrEqualField(String),
+    /// The value must be greater than that of the field named, refer to
+    /// `Rule::EqualsField`.
+    GreaterThanField(String),
+    /// The value must not be less than that of the field named, refer to
+    /// `Rule::EqualsField`.
+    GreaterOrEqualField(String),
+    /// Minimum number of values of a multiple field.
+    ///
+    /// The rules about the items, this one and those below, apply to all the
+    /// values of a multiple field together, once each value is valid. Other
+    /// fields are not limited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, CheckerOption, Rule, Str};
+    /// let mut validator = Validator::new();
+    /// validator
+    ///     .check(Checker::new("tag", "标签", Str)
+    ///            .set(CheckerOption::Multiple(true))
+    ///            .meet(Rule::MinItems(1))
+    ///            .meet(Rule::MaxItems(3))
+    ///            .meet(Rule::UniqueItems));
+    ///
+    /// let mut params = std::collections::HashMap::new();
+    /// params.insert("tag".to_string(), vec!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()]);
+    /// assert_eq!(validator.evaluate(&params).get_error("tag"), "标签不能多于3项");
+    ///
+    /// params.insert("tag".to_string(), vec!["a".to_string(), "b".to_string(), "a".to_string()]);
+    /// assert_eq!(validator.evaluate(&params).get_error("tag"), "标签不能重复");
+    /// ```
+    MinItems(usize),
+    /// Maximum number of values of a multiple field, refer to
+    /// `Rule::MinItems`.
+    MaxItems(usize),
+    /// The values of a multiple field must be different from each other,
+    /// refer to `Rule::MinItems`. Numbers are compared by value.
+    UniqueItems,
+    /// The values of a multiple field must be in ascending order, equal ones
+    /// allowed, refer to `Rule::MinItems`. Values which can't be compared are
+    /// not limited, refer to `Rule::EqualsField`.
+    SortedItems,
+    /// The rule given must not be met, eg. a `Rule::Format` of a forbidden
+    /// pattern.
+    ///
+    /// The rules referencing other fields and those about the items can't
+    /// be combined, by this one or those below, `Checker::meet` panics if so.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use form_checker::{Validator, Checker, Rule, Str};
+    /// let mut validator = Validator::new();
+    /// validator
+    ///     .check(Checker::new("username", "用户名", Str)
+    ///            .meet(Rule::Not(Box::new(Rule::Format("admin".into())))))
+    ///     .check(Checker::new("contact", "联系方式", Str)
+    ///            .meet(Rule::AnyOf(vec![Rule::Format(r"^1\d{10}$".into()),
+    ///                                   Rule::Format(r"^\S+@\S+$".into())])));
+    ///
+    /// let mut params = std::collections::HashMap::new();
+    /// params.insert("username".to_string(), vec!["superadmin".to_string()]);
+    /// params.insert("contact".to_string(), vec!["bob".to_string()]);
+    /// let result = validator.evaluate(&params);
+    /// assert_eq!(result.get_error("username"), "用户名不符合要求");
+    /// assert_eq!(result.get_error("contact"), "联系方式须满足以下条件之一：联系方式格式不正确；联系方式格式不正确");
+    /// ```
+    Not(Box<Rule>),
+    /// At least one of the rules given must be met, refer to `Rule::Not`.
+    AnyOf(Vec<Rule>),
+    /// All the rules given must be met, refer to `Rule::Not`. Unlike adding
+    /// each rule to the checker, the messages of all the rules not met are
+    /// put together as causes of a single message.
+    AllOf(Vec<Rule>),
+    /// The rule given must be met only if the predicate holds for the value,
+    /// eg. a `Rule::Max` for values starting with a prefix.
+    When(Box<dyn Fn(FieldValue) -> bool + Send + Sync>, Box<Rule>),
+    /// A regex pattern to match against the str representation of `FieldValue`,
+    /// refer to `Format`.
+    ///
+    /// `Checker::meet` panics if the pattern is invalid, use
+    /// `Checker::try_meet` for a pattern known at runtime only, eg. one per
+    /// tenant.
+    Format(Format),
     /// A customized lambda, to let you offer your own check logic.
-    Lambda(Box<Fn(FieldValue) -> bool>, Option<Box<Fn(&str, &str, &str) -> String>>)
+    ///
+    /// Use `Rule::Custom` to tell why a value is invalid with a `Message`,
+    /// which the `MessageRenderer` renders.
+    Lambda(Box<dyn Fn(FieldValue) -> bool + Send + Sync>, Option<LambdaMessage>),
+    /// A customized rule, a `CustomRule` or a closure, refer to `CustomRule`.
+    Custom(Box<dyn CustomRule>),
+}
+
+/// A compiled regex pattern for `Rule::Format`.
+///
+/// By default, a value matches if the pattern is found anywhere in it. Call
+/// `full` (or `Format::full_match`) to require the whole value to match.
+///
+/// A `Format` can be made from a `&str` or a `String`, in which case
+/// `Checker::meet` panics if the pattern is invalid, or from a precompiled
+/// `Regex` of the re-exported `regex` crate. Use `Checker::try_meet`, or
+/// `Format::new`, to handle an invalid pattern yourself, eg. when it comes
+/// from configuration.
+///
+/// # Examples
+///
+/// ```
+/// # use form_checker::{Validator, Checker, Rule, Format, Str};
+/// let pattern = String::from(r"l\dy");
+/// let mut validator = Validator::new();
+/// validator
+///     .check(Checker::new("code", "编码", Str)
+///            .meet(Rule::Format(Format::full_match(&pattern).unwrap())));
+///
+/// let mut params = std::collections::HashMap::new();
+/// params.insert("code".to_string(), vec!["xl5yx".to_string()]);
+/// assert!(!validator.evaluate(&params).is_valid());
+///
+/// params.insert("code".to_string(), vec!["l5y".to_string()]);
+/// assert!(validator.evaluate(&params).is_valid());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Format {
+    // An invalid pattern given by `From` is kept until `Checker::meet`,
+    // which knows the field to blame.
+    regex: Result<Regex, regex::Error>,
+}
+
+impl Format {
+    /// Compile a pattern which matches anywhere in the value.
+    pub fn new(pattern: &str) -> Result<Format, regex::Error> {
+        Ok(Format { regex: Ok(Regex::new(pattern)?) })
+    }
+
+    /// Compile a pattern which must match the whole value.
+    pub fn full_match(pattern: &str) -> Result<Format, regex::Error> {
+        Ok(Format { regex: Ok(Regex::new(&format!("^(?:{})$", pattern))?) })
+    }
+
+    /// Require the whole value to match this pattern.
+    pub fn full(self) -> Format {
+        match self.regex {
+            Ok(regex) => Format::full_match(regex.as_str()).expect("anchoring a valid regex"),
+            Err(_) => self,
+        }
+    }
+
+    /// The compiled regex.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pattern given by `From` is not a valid regex.
+    pub fn as_regex(&self) -> &Regex {
+        match self.regex {
+            Ok(ref regex) => regex,
+            Err(ref e) => panic!("invalid format pattern: {}", e),
+        }
+    }
+}
+
+impl From<Regex> for Format {
+    fn from(regex: Regex) -> Format {
+        Format { regex: Ok(regex) }
+    }
+}
+
+impl<'a> From<&'a str> for Format {
+    /// An invalid pattern makes `Checker::meet` panic, use `Format::new` to
+    /// handle it yourself.
+    fn from(pattern: &'a str) -> Format {
+        Format { regex: Regex::new(pattern) }
+    }
+}
+
+impl From<String> for Format {
+    /// An invalid pattern makes `Checker::meet` panic, use `Format::new` to
+    /// handle it yourself.
+    fn from(pattern: String) -> Format {
+        Format::from(pattern.as_str())
+    }
+}
+
+/// Why a rule can't be met, returned by `Checker::try_meet`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RuleError {
+    /// A `Format` made from an invalid pattern, with the error.
+    Format(regex::Error),
+    /// A rule met once all the fields are checked or by all the values
+    /// together, combined by another rule.
+    Combined,
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RuleError::Format(ref e) => write!(f, "invalid format pattern: {}", e),
+            RuleError::Combined => write!(f, "a rule comparing with another field or met by all the values can't be combined"),
+        }
+    }
+}
+
+impl std::error::Error for RuleError {}
+
+/// A customized message builder for `Rule::Lambda`, given the field name, the
+/// field title and the raw value.
+pub type LambdaMessage = Box<dyn Fn(&str, &str, &str) -> String + Send + Sync>;
+
+/// What a `CustomRule` is given besides the value.
+pub struct RuleContext<'a> {
+    /// The field name.
+    pub name: &'a str,
+    /// The field title.
+    pub title: &'a str,
+    /// The raw value, as submitted.
+    pub raw: &'a str,
+}
+
+impl<'a> RuleContext<'a> {
+    /// Construct a kind of message about the value, refer to `Message::some`.
+    pub fn message(&self, kind: MessageKind, rule_values: Vec<String>) -> Message {
+        Message::some(kind, self.name, self.title, Some(self.raw.to_string()), rule_values)
+    }
+}
+
+/// This trait represents a customized rule, refer to `Rule::Custom`.
+///
+/// Unlike `Rule::Lambda`, it tells why a value is invalid with a `Message`,
+/// so it can pick a `MessageKind` and attach rule values, rendered by the
+/// `MessageRenderer` of the validator. It is implemented for closures taking
+/// a `&FieldValue` and a `&RuleContext`, so you can write one either way.
+///
+/// # Examples
+///
+/// ```
+/// # use form_checker::{Validator, Checker, Rule, CustomRule, RuleContext, FieldValue, Message, MessageKind, I64};
+/// struct Even;
+///
+/// impl CustomRule for Even {
+///     fn check(&self, value: &FieldValue, context: &RuleContext) -> Result<(), Message> {
+///         match value.as_i64() {
+///             Some(i) if i % 2 != 0 => Err(context.message(MessageKind::Format, Vec::new())),
+///             _ => Ok(()),
+///         }
+///     }
+/// }
+///
+/// let mut validator = Validator::new();
+/// validator.check(Checker::new("seats", "座位数", I64)
+///                 .meet(Rule::Custom(Box::new(Even)))
+///                 .meet(Rule::Custom(Box::new(|value: &FieldValue, context: &RuleContext| {
+///                     match value.as_i64() {
+///                         Some(i) if i > 8 => Err(context.message(MessageKind::Max, vec!["8".to_string()])),
+///                         _ => Ok(()),
+///                     }
+///                 }))));
+///
+/// let mut params = std::collections::HashMap::new();
+/// params.insert("seats".to_string(), vec!["3".to_string()]);
+/// assert_eq!(validator.evaluate(&params).get_error("seats"), "座位数格式不正确");
+///
+/// params.insert("seats".to_string(), vec!["10".to_string()]);
+/// assert_eq!(validator.evaluate(&params).get_error("seats"), "座位数不能大于8");
+/// ```
+pub trait CustomRule: Send + Sync {
+    fn check(&self, value: &FieldValue, context: &RuleContext) -> Result<(), Message>;
+}
+
+impl<F> CustomRule for F where F: Fn(&FieldValue, &RuleContext) -> Result<(), Message> + Send + Sync {
+    fn check(&self, value: &FieldValue, context: &RuleContext) -> Result<(), Message> {
+        self(value, context)
+    }
 }
 
 /// This trait represents the field type.
 ///
-/// We offer some field types, like `Str`, `I64`, `ChinaMobile` and `Email`.
+/// We offer some field types, like `Str`, `I64`, `F64`, `Decimal`, `Bool`,
+/// `Date`, `Time`, `DateTime`, `Choice`, `ChinaMobile`, `Email` and `File`.
 ///
 /// You just need to implement this trait to transform the raw str value into a 
 /// `FeildValue`.
@@ -627,26 +2539,150 @@ pub enum Rule {
 ///
 /// value is the raw str value from the form.
 ///
-/// And of course you can implement your own field type!
-pub trait FieldType {
+/// And of course you can implement your own field type! Field types are
+/// required to be `Send + Sync`, as checkers are.
+pub trait FieldType: Send + Sync {
+    #[allow(clippy::wrong_self_convention)]
     fn from_str(&self, field_name: &str, field_title: &str, value: &str) -> Result<FieldValue, Message>;
+
+    /// Transform any raw value into a `FieldValue`.
+    ///
+    /// By default, a str value is handed to `from_str` and other values are
+    /// in wrong format. Override it to accept uploaded files, like `File`
+    /// does, or native JSON numbers, like `I64` does.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_raw(&self, field_name: &str, field_title: &str, value: &RawValue) -> Result<FieldValue, Message> {
+        match *value {
+            RawValue::Str(s) => self.from_str(field_name, field_title, s),
+            _ => Err(Message::some(MessageKind::Format,
+                                   field_name,
+                                   field_title,
+                                   Some(value.to_string()),
+                                   Vec::new())),
+        }
+    }
+
+    /// The value of a missing field, which is then valid instead of blank.
+    ///
+    /// By default, None, a missing field is blank. `Bool` takes a missing
+    /// field as false, like an unchecked checkbox. The rules are still met
+    /// against the value. It doesn't apply to an optional field, which is
+    /// still missing, nor to a multiple field, which has no values then.
+    fn missing(&self) -> Option<FieldValue> {
+        None
+    }
 }
 
 /// An enum to represent the primitive value extracted, resulting from applying
 /// a checker.
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum FieldValue {
     /// A str value.
     Str(String),
     /// An integer value as i64.
     I64(i64),
+    /// A floating-point value as f64.
+    F64(f64),
+    /// An exact decimal value.
+    Decimal(DecimalValue),
+    /// A boolean value.
+    Bool(bool),
+    /// A date value.
+    Date(NaiveDate),
+    /// A time of day value.
+    Time(NaiveTime),
+    /// A date and time value, with its offset.
+    DateTime(chrono::DateTime<FixedOffset>),
+    /// An uploaded file.
+    File(UploadedFile),
 }
 
 impl fmt::Display for FieldValue {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             FieldValue::Str(ref s) => { write!(f, "{}", s) },
-            FieldValue::I64(i) => { write!(f, "{}", i.to_string()) }
+            FieldValue::I64(i) => { write!(f, "{}", i) },
+            FieldValue::F64(n) => { write!(f, "{}", n) },
+            FieldValue::Decimal(ref d) => { write!(f, "{}", d) },
+            FieldValue::Bool(b) => { write!(f, "{}", b) },
+            FieldValue::Date(date) => { write!(f, "{}", date) },
+            FieldValue::Time(time) => { write!(f, "{}", time) },
+            FieldValue::DateTime(ref datetime) => { write!(f, "{}", datetime.to_rfc3339()) },
+            FieldValue::File(ref file) => { write!(f, "{}", RawValue::File(file)) },
+        }
+    }
+}
+
+/// Converts a `FieldValue` into a Rust type, None if the variant doesn't fit.
+///
+/// This is what `#[derive(FormCheck)]` of the `form-checker-derive` crate uses
+/// to fill struct fields. Implement it to use your own types there.
+pub trait FromFieldValue: Sized {
+    fn from_field_value(value: FieldValue) -> Option<Self>;
+}
+
+impl FromFieldValue for FieldValue {
+    fn from_field_value(value: FieldValue) -> Option<FieldValue> {
+        Some(value)
+    }
+}
+
+impl FromFieldValue for String {
+    fn from_field_value(value: FieldValue) -> Option<String> {
+        match value {
+            FieldValue::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl FromFieldValue for i64 {
+    fn from_field_value(value: FieldValue) -> Option<i64> {
+        value.as_i64()
+    }
+}
+
+impl FromFieldValue for f64 {
+    fn from_field_value(value: FieldValue) -> Option<f64> {
+        value.as_f64()
+    }
+}
+
+impl FromFieldValue for DecimalValue {
+    fn from_field_value(value: FieldValue) -> Option<DecimalValue> {
+        value.as_decimal()
+    }
+}
+
+impl FromFieldValue for bool {
+    fn from_field_value(value: FieldValue) -> Option<bool> {
+        value.as_bool()
+    }
+}
+
+impl FromFieldValue for NaiveDate {
+    fn from_field_value(value: FieldValue) -> Option<NaiveDate> {
+        value.as_date()
+    }
+}
+
+impl FromFieldValue for NaiveTime {
+    fn from_field_value(value: FieldValue) -> Option<NaiveTime> {
+        value.as_time()
+    }
+}
+
+impl FromFieldValue for chrono::DateTime<FixedOffset> {
+    fn from_field_value(value: FieldValue) -> Option<chrono::DateTime<FixedOffset>> {
+        value.as_datetime()
+    }
+}
+
+impl FromFieldValue for UploadedFile {
+    fn from_field_value(value: FieldValue) -> Option<UploadedFile> {
+        match value {
+            FieldValue::File(file) => Some(file),
+            _ => None,
         }
     }
 }
@@ -668,7 +2704,63 @@ impl FieldValue {
         }
     }
 
-    fn match_rule(&self, field_name: &str, field_title: &str, value: &str, rule: &Rule) -> Result<(), Message> {
+    /// Extract an f64 primitive from the `FieldValue`
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            FieldValue::F64(n) => Some(n),
+            _ => None
+        }
+    }
+
+    /// Extract a decimal value from the `FieldValue`
+    pub fn as_decimal(&self) -> Option<DecimalValue> {
+        match *self {
+            FieldValue::Decimal(d) => Some(d),
+            _ => None
+        }
+    }
+
+    /// Extract a bool primitive from the `FieldValue`
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            FieldValue::Bool(b) => Some(b),
+            _ => None
+        }
+    }
+
+    /// Extract a date from the `FieldValue`
+    pub fn as_date(&self) -> Option<NaiveDate> {
+        match *self {
+            FieldValue::Date(date) => Some(date),
+            _ => None
+        }
+    }
+
+    /// Extract a time of day from the `FieldValue`
+    pub fn as_time(&self) -> Option<NaiveTime> {
+        match *self {
+            FieldValue::Time(time) => Some(time),
+            _ => None
+        }
+    }
+
+    /// Extract a date and time from the `FieldValue`
+    pub fn as_datetime(&self) -> Option<chrono::DateTime<FixedOffset>> {
+        match *self {
+            FieldValue::DateTime(datetime) => Some(datetime),
+            _ => None
+        }
+    }
+
+    /// Extract an uploaded file from the `FieldValue`
+    pub fn as_file(&self) -> Option<UploadedFile> {
+        match *self {
+            FieldValue::File(ref file) => Some(file.clone()),
+            _ => None
+        }
+    }
+
+    fn match_rule(&self, field_name: &str, field_title: &str, value: &str, rule: &Rule, length: LengthMode) -> Result<(), Message> {
         match *rule {
             Rule::Lambda(ref f, ref err_handler) => {
                 if !f(self.clone()) {
@@ -688,9 +2780,79 @@ impl FieldValue {
                     }
                 }
             },
-            Rule::Max(max) => try!(match_max(max, self, field_name, field_title, value)),
-            Rule::Min(min) => try!(match_min(min, self, field_name, field_title, value)),
-            Rule::Format(format) => try!(match_format(format, self, field_name, field_title, value)),
+            Rule::Max(max) => match_max(max, length, self, field_name, field_title, value)?,
+            Rule::Min(min) => match_min(min, length, self, field_name, field_title, value)?,
+            Rule::MaxF64(max) => match_max_f64(max, self, field_name, field_title, value)?,
+            Rule::MinF64(min) => match_min_f64(min, self, field_name, field_title, value)?,
+            Rule::MaxDecimal(max) => match_max_decimal(max, self, field_name, field_title, value)?,
+            Rule::MinDecimal(min) => match_min_decimal(min, self, field_name, field_title, value)?,
+            Rule::Scale(scale) => match_scale(scale, self, field_name, field_title, value)?,
+            Rule::Precision(precision) => match_precision(precision, self, field_name, field_title, value)?,
+            Rule::Before(ref bound) => datetime::match_before(bound, self, field_name, field_title, value)?,
+            Rule::After(ref bound) => datetime::match_after(bound, self, field_name, field_title, value)?,
+            Rule::NotInFuture => datetime::match_not_in_future(self, field_name, field_title, value)?,
+            Rule::NotInPast => datetime::match_not_in_past(self, field_name, field_title, value)?,
+            Rule::Within(window) => datetime::match_within(window, self, field_name, field_title, value)?,
+            Rule::MinAge(age) => datetime::match_min_age(age, self, field_name, field_title, value)?,
+            Rule::Finite => match_finite(self, field_name, field_title, value)?,
+            Rule::OneOf(ref values) => match_one_of(values, self, field_name, field_title, value)?,
+            Rule::NoneOf(ref values) => match_none_of(values, self, field_name, field_title, value)?,
+            // Met once all the fields are checked, refer to `match_field`.
+            Rule::EqualsField(_) | Rule::NotEqualsField(_) | Rule::LessThanField(_)
+                | Rule::LessOrEqualField(_) | Rule::GreaterThanField(_) | Rule::GreaterOrEqualField(_) => {},
+            // Met by all the values together, refer to `match_items`.
+            Rule::MinItems(_) | Rule::MaxItems(_) | Rule::UniqueItems | Rule::SortedItems => {},
+            Rule::Not(ref rule) => {
+                if self.match_rule(field_name, field_title, value, rule, length).is_ok() {
+                    return Err(Message::some(MessageKind::Not,
+                                            field_name,
+                                            field_title,
+                                            Some(value.to_string()),
+                                            Vec::new()));
+                }
+            },
+            Rule::AnyOf(ref rules) => {
+                let mut causes = Vec::new();
+                for rule in rules {
+                    match self.match_rule(field_name, field_title, value, rule, length) {
+                        Ok(()) => return Ok(()),
+                        Err(cause) => causes.push(cause),
+                    }
+                }
+                if !causes.is_empty() {
+                    return Err(Message::some(MessageKind::AnyOf,
+                                            field_name,
+                                            field_title,
+                                            Some(value.to_string()),
+                                            Vec::new()).caused_by(causes));
+                }
+            },
+            Rule::AllOf(ref rules) => {
+                let causes: Vec<Message> = rules.iter()
+                    .filter_map(|rule| self.match_rule(field_name, field_title, value, rule, length).err())
+                    .collect();
+                if !causes.is_empty() {
+                    return Err(Message::some(MessageKind::AllOf,
+                                            field_name,
+                                            field_title,
+                                            Some(value.to_string()),
+                                            Vec::new()).caused_by(causes));
+                }
+            },
+            Rule::Custom(ref rule) => {
+                let context = RuleContext {
+                    name: field_name,
+                    title: field_title,
+                    raw: value,
+                };
+                rule.check(self, &context)?;
+            },
+            Rule::When(ref predicate, ref rule) => {
+                if predicate(self.clone()) {
+                    self.match_rule(field_name, field_title, value, rule, length)?;
+                }
+            },
+            Rule::Format(ref format) => match_format(format.as_regex(), self, field_name, field_title, value)?,
         }
 
     Ok(())
@@ -698,10 +2860,10 @@ impl FieldValue {
     }
 }
 
-fn match_max(max: i64, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+fn match_max(max: i64, length: LengthMode, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
     match *value {
         FieldValue::Str(ref s) => {
-            if s.len() > max as usize {
+            if length.count(s) > max as usize {
                 return Err(Message::some(MessageKind::MaxLen,
                                         field_name,
                                         field_title,
@@ -718,14 +2880,42 @@ fn match_max(max: i64, value: &FieldValue, field_name: &str, field_title: &str,
                                         vec![max.to_string()]));
             }
         },
+        FieldValue::F64(n) => {
+            if n > max as f64 {
+                return Err(Message::some(MessageKind::Max,
+                                        field_name,
+                                        field_title,
+                                        Some(raw.to_string()),
+                                        vec![max.to_string()]));
+            }
+        },
+        FieldValue::Decimal(d) => {
+            if d > DecimalValue::from(max) {
+                return Err(Message::some(MessageKind::Max,
+                                        field_name,
+                                        field_title,
+                                        Some(raw.to_string()),
+                                        vec![max.to_string()]));
+            }
+        },
+        FieldValue::File(ref file) => {
+            if file.data.len() > max as usize {
+                return Err(Message::some(MessageKind::MaxLen,
+                                        field_name,
+                                        field_title,
+                                        Some(raw.to_string()),
+                                        vec![max.to_string()]));
+            }
+        },
+        FieldValue::Bool(_) | FieldValue::Date(_) | FieldValue::Time(_) | FieldValue::DateTime(_) => {},
     }
     Ok(())
 }
 
-fn match_min(min: i64, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+fn match_min(min: i64, length: LengthMode, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
     match *value {
         FieldValue::Str(ref s) => {
-            if s.len() < min as usize {
+            if length.count(s) < min as usize {
                 return Err(Message::some(MessageKind::MinLen,
                                         field_name,
                                         field_title,
@@ -742,12 +2932,150 @@ fn match_min(min: i64, value: &FieldValue, field_name: &str, field_title: &str,
                                         vec![min.to_string()]));
             }
         },
+        FieldValue::F64(n) => {
+            if n < min as f64 {
+                return Err(Message::some(MessageKind::Min,
+                                        field_name,
+                                        field_title,
+                                        Some(raw.to_string()),
+                                        vec![min.to_string()]));
+            }
+        },
+        FieldValue::Decimal(d) => {
+            if d < DecimalValue::from(min) {
+                return Err(Message::some(MessageKind::Min,
+                                        field_name,
+                                        field_title,
+                                        Some(raw.to_string()),
+                                        vec![min.to_string()]));
+            }
+        },
+        FieldValue::File(ref file) => {
+            if file.data.len() < min as usize {
+                return Err(Message::some(MessageKind::MinLen,
+                                        field_name,
+                                        field_title,
+                                        Some(raw.to_string()),
+                                        vec![min.to_string()]));
+            }
+        },
+        FieldValue::Bool(_) | FieldValue::Date(_) | FieldValue::Time(_) | FieldValue::DateTime(_) => {},
+    }
+    Ok(())
+}
+
+fn match_max_f64(max: f64, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    let n = match *value {
+        FieldValue::I64(i) => i as f64,
+        FieldValue::F64(n) => n,
+        _ => return Ok(()),
+    };
+    if n > max {
+        return Err(Message::some(MessageKind::Max,
+                                field_name,
+                                field_title,
+                                Some(raw.to_string()),
+                                vec![max.to_string()]));
+    }
+    Ok(())
+}
+
+fn match_min_f64(min: f64, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    let n = match *value {
+        FieldValue::I64(i) => i as f64,
+        FieldValue::F64(n) => n,
+        _ => return Ok(()),
+    };
+    if n < min {
+        return Err(Message::some(MessageKind::Min,
+                                field_name,
+                                field_title,
+                                Some(raw.to_string()),
+                                vec![min.to_string()]));
+    }
+    Ok(())
+}
+
+fn match_max_decimal(max: DecimalValue, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    if let FieldValue::Decimal(d) = *value {
+        if d > max {
+            return Err(Message::some(MessageKind::Max,
+                                    field_name,
+                                    field_title,
+                                    Some(raw.to_string()),
+                                    vec![max.to_string()]));
+        }
+    }
+    Ok(())
+}
+
+fn match_min_decimal(min: DecimalValue, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    if let FieldValue::Decimal(d) = *value {
+        if d < min {
+            return Err(Message::some(MessageKind::Min,
+                                    field_name,
+                                    field_title,
+                                    Some(raw.to_string()),
+                                    vec![min.to_string()]));
+        }
+    }
+    Ok(())
+}
+
+fn match_scale(scale: u32, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    let places = match *value {
+        FieldValue::F64(_) => decimal_places(raw),
+        FieldValue::Decimal(d) => d.scale() as i64,
+        _ => return Ok(()),
+    };
+    if places > scale as i64 {
+        return Err(Message::some(MessageKind::Format,
+                                field_name,
+                                field_title,
+                                Some(raw.to_string()),
+                                vec![scale.to_string()]));
+    }
+    Ok(())
+}
+
+fn match_precision(precision: u32, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    if let FieldValue::Decimal(d) = *value {
+        if d.precision() > precision {
+            return Err(Message::some(MessageKind::Format,
+                                    field_name,
+                                    field_title,
+                                    Some(raw.to_string()),
+                                    vec![precision.to_string()]));
+        }
+    }
+    Ok(())
+}
+
+fn match_finite(value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    if let FieldValue::F64(n) = *value {
+        if !n.is_finite() {
+            return Err(Message::some(MessageKind::Format,
+                                    field_name,
+                                    field_title,
+                                    Some(raw.to_string()),
+                                    Vec::new()));
+        }
     }
     Ok(())
 }
 
-fn match_format(format: &str, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
-    let re = Regex::new(format).unwrap();
+/// The number of digits after the decimal point of a number literal, taking
+/// the exponent into account, eg. 3 for `1.5e-2`.
+fn decimal_places(raw: &str) -> i64 {
+    let (mantissa, exponent) = match raw.find(['e', 'E']) {
+        Some(i) => (&raw[..i], raw[i + 1..].parse::<i64>().unwrap_or(0)),
+        None => (raw, 0),
+    };
+    let fraction = mantissa.find('.').map_or(0, |i| mantissa.len() - i - 1) as i64;
+    (fraction - exponent).max(0)
+}
+
+fn match_format(re: &Regex, value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
     if !re.is_match(&value.to_string()) {
         return Err(Message::some(MessageKind::Format,
                                 field_name,
@@ -758,6 +3086,143 @@ fn match_format(format: &str, value: &FieldValue, field_name: &str, field_title:
     Ok(())
 }
 
+impl Rule {
+    /// Tell why a rule can't be met, if so.
+    fn check_rule(&self) -> Result<(), RuleError> {
+        match *self {
+            Rule::Format(Format { regex: Err(ref e) }) => Err(RuleError::Format(e.clone())),
+            Rule::Not(ref rule) | Rule::When(_, ref rule) => rule.check_combined(),
+            Rule::AnyOf(ref rules) | Rule::AllOf(ref rules) => rules.iter().try_for_each(Rule::check_combined),
+            _ => Ok(()),
+        }
+    }
+
+    /// Like `check_rule`, for a rule combined by another one, which is met
+    /// value by value only.
+    fn check_combined(&self) -> Result<(), RuleError> {
+        if self.is_deferred() {
+            return Err(RuleError::Combined);
+        }
+        self.check_rule()
+    }
+
+    /// True if a rule is not met value by value, but once all the fields are
+    /// checked or by all the values together.
+    fn is_deferred(&self) -> bool {
+        match *self {
+            Rule::MinItems(_) | Rule::MaxItems(_) | Rule::UniqueItems | Rule::SortedItems => true,
+            _ => self.other_field().is_some(),
+        }
+    }
+
+    /// The name of the other field a rule references, if any.
+    fn other_field(&self) -> Option<&str> {
+        match *self {
+            Rule::EqualsField(ref name) | Rule::NotEqualsField(ref name) | Rule::LessThanField(ref name)
+                | Rule::LessOrEqualField(ref name) | Rule::GreaterThanField(ref name)
+                | Rule::GreaterOrEqualField(ref name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+/// Compare two values of the same kind, or two numbers, None if they can't
+/// be compared.
+fn compare_values(a: &FieldValue, b: &FieldValue) -> Option<Ordering> {
+    match (a, b) {
+        (FieldValue::Str(a), FieldValue::Str(b)) => Some(a.cmp(b)),
+        (FieldValue::Bool(a), FieldValue::Bool(b)) => Some(a.cmp(b)),
+        (FieldValue::I64(a), FieldValue::I64(b)) => Some(a.cmp(b)),
+        (FieldValue::F64(a), FieldValue::F64(b)) => a.partial_cmp(b),
+        (FieldValue::Decimal(a), FieldValue::Decimal(b)) => Some(a.cmp(b)),
+        (&FieldValue::I64(a), &FieldValue::F64(b)) => (a as f64).partial_cmp(&b),
+        (&FieldValue::I64(a), &FieldValue::Decimal(b)) => Some(DecimalValue::from(a).cmp(&b)),
+        (&FieldValue::F64(a), &FieldValue::Decimal(b)) => a.partial_cmp(&b.to_string().parse().ok()?),
+        (FieldValue::F64(_), FieldValue::I64(_)) | (FieldValue::Decimal(_), FieldValue::I64(_))
+            | (FieldValue::Decimal(_), FieldValue::F64(_)) => compare_values(b, a).map(Ordering::reverse),
+        _ => datetime::compare(a, b),
+    }
+}
+
+/// Meet a rule referencing another field, given the title and the value of
+/// the other field.
+fn match_field(rule: &Rule, value: &FieldValue, other_title: &str, other: &FieldValue, field_name: &str, field_title: &str) -> Result<(), Message> {
+    let ordering = compare_values(value, other);
+    let equal = value == other || ordering == Some(Ordering::Equal);
+    let (kind, met) = match *rule {
+        Rule::EqualsField(_) => (MessageKind::EqualsField, equal),
+        Rule::NotEqualsField(_) => (MessageKind::NotEqualsField, !equal),
+        Rule::LessThanField(_) => (MessageKind::LessThanField, ordering == Some(Ordering::Less)),
+        Rule::LessOrEqualField(_) => (MessageKind::LessOrEqualField, matches!(ordering, Some(Ordering::Less) | Some(Ordering::Equal))),
+        Rule::GreaterThanField(_) => (MessageKind::GreaterThanField, ordering == Some(Ordering::Greater)),
+        Rule::GreaterOrEqualField(_) => (MessageKind::GreaterOrEqualField, matches!(ordering, Some(Ordering::Greater) | Some(Ordering::Equal))),
+        _ => return Ok(()),
+    };
+    if !met {
+        return Err(Message::some(kind,
+                                field_name,
+                                field_title,
+                                Some(value.to_string()),
+                                vec![other_title.to_string(), other.to_string()]));
+    }
+    Ok(())
+}
+
+/// Meet a rule about the items of a multiple field.
+fn match_items(rule: &Rule, values: &[FieldValue], field_name: &str, field_title: &str) -> Result<(), Message> {
+    let equal = |a: &FieldValue, b: &FieldValue| a == b || compare_values(a, b) == Some(Ordering::Equal);
+    let message = |kind, index: Option<usize>, rule_values| {
+        let message = Message::some(kind,
+                                    field_name,
+                                    field_title,
+                                    index.map(|i| values[i].to_string()),
+                                    rule_values);
+        match index {
+            Some(i) => message.at(i),
+            None => message,
+        }
+    };
+    match *rule {
+        Rule::MinItems(min) if values.len() < min => Err(message(MessageKind::MinItems, None, vec![min.to_string()])),
+        Rule::MaxItems(max) if values.len() > max => Err(message(MessageKind::MaxItems, None, vec![max.to_string()])),
+        Rule::UniqueItems => {
+            match (1..values.len()).find(|&i| values[..i].iter().any(|v| equal(v, &values[i]))) {
+                Some(i) => Err(message(MessageKind::UniqueItems, Some(i), vec![values[i].to_string()])),
+                None => Ok(()),
+            }
+        },
+        Rule::SortedItems => {
+            match (1..values.len()).find(|&i| compare_values(&values[i - 1], &values[i]) == Some(Ordering::Greater)) {
+                Some(i) => Err(message(MessageKind::SortedItems, Some(i), Vec::new())),
+                None => Ok(()),
+            }
+        },
+        _ => Ok(()),
+    }
+}
+
+fn match_one_of(values: &[String], value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    if !values.contains(&value.to_string()) {
+        return Err(Message::some(MessageKind::OneOf,
+                                field_name,
+                                field_title,
+                                Some(raw.to_string()),
+                                values.to_vec()));
+    }
+    Ok(())
+}
+
+fn match_none_of(values: &[String], value: &FieldValue, field_name: &str, field_title: &str, raw: &str) -> Result<(), Message> {
+    if values.contains(&value.to_string()) {
+        return Err(Message::some(MessageKind::NoneOf,
+                                field_name,
+                                field_title,
+                                Some(raw.to_string()),
+                                values.to_vec()));
+    }
+    Ok(())
+}
+
 /// A general field type to represent a string field.
 pub struct Str;
 
@@ -781,6 +3246,295 @@ impl FieldType for I64 {
                                     Vec::new())),
         }
     }
+
+    fn from_raw(&self, field_name: &str, field_title: &str, value: &RawValue) -> Result<FieldValue, Message> {
+        match *value {
+            RawValue::Str(s) => self.from_str(field_name, field_title, s),
+            RawValue::Int(i) => Ok(FieldValue::I64(i)),
+            _ => Err(Message::some(MessageKind::Format,
+                                   field_name,
+                                   field_title,
+                                   Some(value.to_string()),
+                                   Vec::new())),
+        }
+    }
+}
+
+/// A field type to represent a floating-point field.
+///
+/// A value like `-1.5`, `.5` or `2` is accepted, and so are `NaN`, `inf` and
+/// `infinity` in any case, which `Rule::Finite` rejects. Exponent notation,
+/// like `1.5e3`, is rejected unless enabled by `exponent`.
+///
+/// # Examples
+///
+/// ```
+/// # use form_checker::{Validator, Checker, Rule, F64};
+/// let mut validator = Validator::new();
+/// validator
+///     .check(Checker::new("price", "价格", F64::new())
+///            .meet(Rule::Finite)
+///            .meet(Rule::Scale(2))
+///            .meet(Rule::MinF64(0.01))
+///            .meet(Rule::MaxF64(999.99)));
+///
+/// let mut params = std::collections::HashMap::new();
+/// params.insert("price".to_string(), vec!["9.99".to_string()]);
+/// assert_eq!(validator.evaluate(&params).try_get_f64("price"), Ok(9.99));
+///
+/// params.insert("price".to_string(), vec!["9.999".to_string()]);
+/// assert_eq!(validator.evaluate(&params).get_error("price"), "价格格式不正确");
+///
+/// params.insert("price".to_string(), vec!["1000".to_string()]);
+/// assert_eq!(validator.evaluate(&params).get_error("price"), "价格不能大于999.99");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct F64 {
+    exponent: bool,
+}
+
+impl F64 {
+    /// Construct a `F64` which rejects exponent notation.
+    pub fn new() -> F64 {
+        F64::default()
+    }
+
+    /// True means exponent notation, like `1.5e3`, is accepted.
+    pub fn exponent(mut self, exponent: bool) -> F64 {
+        self.exponent = exponent;
+        self
+    }
+
+    fn is_literal(&self, value: &str) -> bool {
+        let value = value.strip_prefix(['+', '-']).unwrap_or(value);
+        if ["nan", "inf", "infinity"].iter().any(|special| value.eq_ignore_ascii_case(special)) {
+            return true;
+        }
+        let (mantissa, exponent) = match value.find(['e', 'E']) {
+            Some(i) if self.exponent => (&value[..i], Some(&value[i + 1..])),
+            _ => (value, None),
+        };
+        let digits = mantissa.bytes().filter(u8::is_ascii_digit).count();
+        let points = mantissa.bytes().filter(|&b| b == b'.').count();
+        if digits == 0 || points > 1 || digits + points != mantissa.len() {
+            return false;
+        }
+        match exponent {
+            Some(exponent) => {
+                let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+                !exponent.is_empty() && exponent.bytes().all(|b| b.is_ascii_digit())
+            },
+            None => true,
+        }
+    }
+}
+
+impl FieldType for F64 {
+    fn from_str(&self, field_name: &str, field_title: &str, value: &str) -> Result<FieldValue, Message> {
+        match value.parse::<f64>() {
+            Ok(n) if self.is_literal(value) => Ok(FieldValue::F64(n)),
+            _ => Err(Message::some(MessageKind::Format,
+                                   field_name,
+                                   field_title,
+                                   Some(value.to_string()),
+                                   Vec::new())),
+        }
+    }
+
+    fn from_raw(&self, field_name: &str, field_title: &str, value: &RawValue) -> Result<FieldValue, Message> {
+        match *value {
+            RawValue::Str(s) => self.from_str(field_name, field_title, s),
+            RawValue::Int(i) => Ok(FieldValue::F64(i as f64)),
+            RawValue::Float(n) => Ok(FieldValue::F64(n)),
+            _ => Err(Message::some(MessageKind::Format,
+                                   field_name,
+                                   field_title,
+                                   Some(value.to_string()),
+                                   Vec::new())),
+        }
+    }
+}
+
+/// A field type to represent a boolean field, eg. a checkbox.
+///
+/// A value is true if it is one of the truthy words, default `on`, `true`,
+/// `1` and `yes`, and false if it is one of the falsy words, default `off`,
+/// `false`, `0` and `no`, compared case-insensitively. Other values are in
+/// wrong format.
+///
+/// An unchecked checkbox is not submitted at all, so a missing field is
+/// false rather than blank, refer to `FieldType::missing`.
+///
+/// # Examples
+///
+/// ```
+/// # use form_checker::{Validator, Checker, Bool};
+/// let mut validator = Validator::new();
+/// validator
+///     .check(Checker::new("subscribe", "订阅", Bool::new()))
+///     .check(Checker::new("agree", "同意", Bool::new().truthy(&["y"]).falsy(&["n"])));
+///
+/// let mut params = std::collections::HashMap::new();
+/// params.insert("agree".to_string(), vec!["Y".to_string()]);
+/// let result = validator.evaluate(&params);
+/// assert_eq!(result.try_get_bool("subscribe"), Ok(false));
+/// assert_eq!(result.try_get_bool("agree"), Ok(true));
+///
+/// params.insert("agree".to_string(), vec!["yes".to_string()]);
+/// assert_eq!(validator.evaluate(&params).get_error("agree"), "同意格式不正确");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Bool {
+    truthy: Vec<String>,
+    falsy: Vec<String>,
+}
+
+impl Default for Bool {
+    fn default() -> Bool {
+        Bool::new()
+    }
+}
+
+impl Bool {
+    /// Construct a `Bool` with the default words.
+    pub fn new() -> Bool {
+        Bool {
+            truthy: vec!["on".to_string(), "true".to_string(), "1".to_string(), "yes".to_string()],
+            falsy: vec!["off".to_string(), "false".to_string(), "0".to_string(), "no".to_string()],
+        }
+    }
+
+    /// Replace the words meaning true.
+    pub fn truthy(mut self, words: &[&str]) -> Bool {
+        self.truthy = words.iter().map(|w| w.to_string()).collect();
+        self
+    }
+
+    /// Replace the words meaning false.
+    pub fn falsy(mut self, words: &[&str]) -> Bool {
+        self.falsy = words.iter().map(|w| w.to_string()).collect();
+        self
+    }
+}
+
+impl FieldType for Bool {
+    fn from_str(&self, field_name: &str, field_title: &str, value: &str) -> Result<FieldValue, Message> {
+        if self.truthy.iter().any(|w| w.eq_ignore_ascii_case(value)) {
+            return Ok(FieldValue::Bool(true));
+        }
+        if self.falsy.iter().any(|w| w.eq_ignore_ascii_case(value)) {
+            return Ok(FieldValue::Bool(false));
+        }
+        Err(Message::some(MessageKind::Format,
+                          field_name,
+                          field_title,
+                          Some(value.to_string()),
+                          Vec::new()))
+    }
+
+    fn from_raw(&self, field_name: &str, field_title: &str, value: &RawValue) -> Result<FieldValue, Message> {
+        match *value {
+            RawValue::Bool(b) => Ok(FieldValue::Bool(b)),
+            _ => self.from_str(field_name, field_title, &value.to_string()),
+        }
+    }
+
+    fn missing(&self) -> Option<FieldValue> {
+        Some(FieldValue::Bool(false))
+    }
+}
+
+/// A field type to represent a value from a fixed set, eg. a select box or a
+/// radio group.
+///
+/// The value is a `FieldValue::Str` of the canonical value. A value can be
+/// matched ignoring case, and other submitted values can be mapped to a
+/// canonical one by `alias`. A value not allowed raises `MessageKind::OneOf`
+/// with the canonical values.
+///
+/// # Examples
+///
+/// ```
+/// # use form_checker::{Validator, Checker, Choice};
+/// let mut validator = Validator::new();
+/// validator.check(Checker::new("color", "颜色", Choice::new(&["red", "green"])
+///                              .ignore_case(true)
+///                              .alias("绿", "green")));
+///
+/// let mut params = std::collections::HashMap::new();
+/// params.insert("color".to_string(), vec!["RED".to_string()]);
+/// assert_eq!(validator.evaluate(&params).try_get_str("color"), Ok("red".to_string()));
+///
+/// params.insert("color".to_string(), vec!["绿".to_string()]);
+/// assert_eq!(validator.evaluate(&params).try_get_str("color"), Ok("green".to_string()));
+///
+/// params.insert("color".to_string(), vec!["blue".to_string()]);
+/// assert_eq!(validator.evaluate(&params).get_error("color"), "颜色必须是red、green之一");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Choice {
+    values: Vec<String>,
+    aliases: Vec<(String, String)>,
+    ignore_case: bool,
+}
+
+impl Choice {
+    /// Construct a `Choice` of the canonical values given.
+    pub fn new(values: &[&str]) -> Choice {
+        Choice {
+            values: values.iter().map(|v| v.to_string()).collect(),
+            ..Choice::default()
+        }
+    }
+
+    /// True means values are matched ignoring case, default false.
+    pub fn ignore_case(mut self, ignore_case: bool) -> Choice {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// Accept `submitted` as the canonical value `canonical`, eg. `"1"` for
+    /// `"male"`.
+    pub fn alias(mut self, submitted: &str, canonical: &str) -> Choice {
+        self.aliases.push((submitted.to_string(), canonical.to_string()));
+        self
+    }
+
+    fn matches(&self, a: &str, b: &str) -> bool {
+        if self.ignore_case {
+            a.to_lowercase() == b.to_lowercase()
+        } else {
+            a == b
+        }
+    }
+}
+
+impl FieldType for Choice {
+    fn from_str(&self, field_name: &str, field_title: &str, value: &str) -> Result<FieldValue, Message> {
+        let canonical = self.values.iter()
+            .find(|v| self.matches(v, value))
+            .or_else(|| self.aliases.iter().find(|&(submitted, _)| self.matches(submitted, value)).map(|(_, canonical)| canonical));
+        match canonical {
+            Some(canonical) => Ok(FieldValue::Str(canonical.clone())),
+            None => Err(Message::some(MessageKind::OneOf,
+                                      field_name,
+                                      field_title,
+                                      Some(value.to_string()),
+                                      self.values.clone())),
+        }
+    }
+
+    fn from_raw(&self, field_name: &str, field_title: &str, value: &RawValue) -> Result<FieldValue, Message> {
+        match *value {
+            RawValue::Str(s) => self.from_str(field_name, field_title, s),
+            RawValue::Int(_) | RawValue::Float(_) | RawValue::Bool(_) => self.from_str(field_name, field_title, &value.to_string()),
+            _ => Err(Message::some(MessageKind::Format,
+                                   field_name,
+                                   field_title,
+                                   Some(value.to_string()),
+                                   Vec::new())),
+        }
+    }
 }
 
 /// A field type to represent a mobile number used in China.
@@ -788,7 +3542,8 @@ pub struct ChinaMobile;
 
 impl FieldType for ChinaMobile {
     fn from_str(&self, field_name: &str, field_title: &str, value: &str) -> Result<FieldValue, Message> {
-        let re = Regex::new(r"^1\d{10}$").unwrap();
+        static RE: OnceLock<Regex> = OnceLock::new();
+        let re = RE.get_or_init(|| Regex::new(r"^1\d{10}$").unwrap());
         if !re.is_match(value) {
             return Err(Message::some(MessageKind::Format,
                                     field_name,
@@ -805,7 +3560,8 @@ pub struct Email;
 
 impl FieldType for Email {
     fn from_str(&self, field_name: &str, field_title: &str, value: &str) -> Result<FieldValue, Message> {
-        let re = Regex::new(r"(?i)^[\w.%+-]+@(?:[A-Z0-9-]+\.)+[A-Z]{2,4}$").unwrap();
+        static RE: OnceLock<Regex> = OnceLock::new();
+        let re = RE.get_or_init(|| Regex::new(r"(?i)^[\w.%+-]+@(?:[A-Z0-9-]+\.)+[A-Z]{2,4}$").unwrap());
         if !re.is_match(value) {
             return Err(Message::some(MessageKind::Format,
                                     field_name,
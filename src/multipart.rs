@@ -0,0 +1,411 @@
+//! Decoding of `multipart/form-data` bodies, and the `File` field type.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use nested;
+use {Children, FieldType, FieldValue, Limits, Message, MessageKind, ParseError, RawValue, Source};
+
+/// A file uploaded in a `multipart/form-data` body.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UploadedFile {
+    /// The file name given by the client, if any.
+    pub filename: Option<String>,
+    /// The content type declared by the client, if any.
+    pub content_type: Option<String>,
+    /// The file content.
+    pub data: Vec<u8>,
+}
+
+/// Params decoded from a `multipart/form-data` body.
+///
+/// Text parts are kept in `fields` and file parts in `files`, both looked up
+/// by checkers as a `Source`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Form {
+    pub fields: HashMap<String, Vec<String>>,
+    pub files: HashMap<String, Vec<UploadedFile>>,
+}
+
+impl Source for Form {
+    fn get(&self, name: &str) -> Option<Vec<RawValue<'_>>> {
+        let fields = nested::lookup(&self.fields, name);
+        let files = nested::lookup(&self.files, name);
+        if fields.is_none() && files.is_none() {
+            return None;
+        }
+        let mut values: Vec<RawValue> = fields.into_iter().flatten().map(|v| RawValue::Str(v)).collect();
+        values.extend(files.into_iter().flatten().map(RawValue::File));
+        Some(values)
+    }
+
+    fn child(&self, name: &str) -> Option<Box<dyn Source + '_>> {
+        let child = Form {
+            fields: nested::child_map(&self.fields, name),
+            files: nested::child_map(&self.files, name),
+        };
+        if child.fields.is_empty() && child.files.is_empty() {
+            return None;
+        }
+        Some(Box::new(child))
+    }
+
+    fn children(&self, name: &str) -> Option<Children<'_>> {
+        let mut fields = nested::item_maps(&self.fields, name);
+        let mut files = nested::item_maps(&self.files, name);
+        let mut keys: Vec<usize> = fields.keys().chain(files.keys()).cloned().collect();
+        if keys.is_empty() {
+            return None;
+        }
+        keys.sort();
+        keys.dedup();
+        Some(keys.into_iter().map(|i| {
+            let item = Form {
+                fields: fields.remove(&i).unwrap_or_default(),
+                files: files.remove(&i).unwrap_or_default(),
+            };
+            (i.to_string(), Box::new(item) as Box<dyn Source>)
+        }).collect())
+    }
+}
+
+/// Extract the boundary from a `multipart/form-data` content type header.
+pub fn multipart_boundary(content_type: &str) -> Option<&str> {
+    let mut params = split_params(content_type).into_iter();
+    if !params.next()?.trim().eq_ignore_ascii_case("multipart/form-data") {
+        return None;
+    }
+    // A boundary can't hold a quote or a backslash, so it is never unescaped.
+    params
+        .filter_map(split_param)
+        .find(|(key, _)| key.eq_ignore_ascii_case("boundary"))
+        .and_then(|(_, value)| match value {
+            Cow::Borrowed(value) => Some(value),
+            Cow::Owned(_) => None,
+        })
+        .filter(|boundary| !boundary.is_empty())
+}
+
+/// Decode a `multipart/form-data` body into a `Form`.
+///
+/// A part with a `filename` goes to `files`, other parts go to `fields`. A file
+/// input left empty by the user, which browsers send as an empty part with an
+/// empty file name, is ignored.
+///
+/// `Limits::max_length` applies to the whole body, `Limits::max_pairs` to
+/// the number of parts and `Limits::max_value_length` to the fields, but not
+/// to the files.
+///
+/// # Examples
+///
+/// ```
+/// # use form_checker::{parse_multipart, Limits};
+/// let body = b"--XyZ\r\n\
+///              Content-Disposition: form-data; name=\"name\"\r\n\r\n\
+///              bob\r\n\
+///              --XyZ\r\n\
+///              Content-Disposition: form-data; name=\"avatar\"; filename=\"a.txt\"\r\n\
+///              Content-Type: text/plain\r\n\r\n\
+///              hello\r\n\
+///              --XyZ--\r\n";
+/// let form = parse_multipart(body, "XyZ", &Limits::default()).unwrap();
+/// assert_eq!(form.fields["name"], vec!["bob".to_string()]);
+/// assert_eq!(form.files["avatar"][0].data, b"hello".to_vec());
+/// ```
+pub fn parse_multipart(body: &[u8], boundary: &str, limits: &Limits) -> Result<Form, ParseError> {
+    if body.len() > limits.max_length {
+        return Err(ParseError::TooLong);
+    }
+
+    let delimiter = format!("--{}", boundary).into_bytes();
+    let next_delimiter = format!("\r\n--{}", boundary).into_bytes();
+
+    let mut form = Form::default();
+    let mut rest = match find(body, &delimiter) {
+        Some(i) => &body[i + delimiter.len()..],
+        None => return Err(ParseError::Malformed("boundary not found".to_string())),
+    };
+    let mut parts = 0;
+    loop {
+        if rest.starts_with(b"--") {
+            return Ok(form);
+        }
+        rest = match rest.strip_prefix(b"\r\n") {
+            Some(rest) => rest,
+            None => return Err(ParseError::Malformed("missing line break after boundary".to_string())),
+        };
+
+        parts += 1;
+        if parts > limits.max_pairs {
+            return Err(ParseError::TooManyPairs);
+        }
+
+        let header_end = match find(rest, b"\r\n\r\n") {
+            Some(i) => i,
+            None => return Err(ParseError::Malformed("unterminated part headers".to_string())),
+        };
+        let headers = String::from_utf8_lossy(&rest[..header_end]).into_owned();
+        rest = &rest[header_end + 4..];
+
+        let data_end = match find(rest, &next_delimiter) {
+            Some(i) => i,
+            None => return Err(ParseError::Malformed("unterminated part".to_string())),
+        };
+        let data = &rest[..data_end];
+        rest = &rest[data_end + next_delimiter.len()..];
+
+        add_part(&mut form, &headers, data, limits)?;
+    }
+}
+
+fn add_part(form: &mut Form, headers: &str, data: &[u8], limits: &Limits) -> Result<(), ParseError> {
+    let mut name = None;
+    let mut filename = None;
+    let mut content_type = None;
+    for line in headers.split("\r\n") {
+        let (header, value) = match line.find(':') {
+            Some(i) => (line[..i].trim(), line[i + 1..].trim()),
+            None => continue,
+        };
+        if header.eq_ignore_ascii_case("content-disposition") {
+            for (key, value) in split_params(value).into_iter().skip(1).filter_map(split_param) {
+                if key.eq_ignore_ascii_case("name") {
+                    name = Some(value.into_owned());
+                } else if key.eq_ignore_ascii_case("filename") {
+                    filename = Some(value.into_owned());
+                }
+            }
+        } else if header.eq_ignore_ascii_case("content-type") {
+            content_type = Some(value.to_string());
+        }
+    }
+
+    let name = match name {
+        Some(name) => name,
+        None => return Err(ParseError::Malformed("part without a name".to_string())),
+    };
+    if name.len() > limits.max_key_length {
+        return Err(ParseError::KeyTooLong);
+    }
+    let values = form.fields.get(&name).map_or(0, Vec::len) + form.files.get(&name).map_or(0, Vec::len);
+    if values >= limits.max_values {
+        return Err(ParseError::TooManyValues(name));
+    }
+    match filename {
+        Some(ref filename) if filename.is_empty() && data.is_empty() => {},
+        Some(filename) => {
+            form.files.entry(name).or_default().push(UploadedFile {
+                filename: if filename.is_empty() { None } else { Some(filename) },
+                content_type,
+                data: data.to_vec(),
+            });
+        },
+        None if data.len() > limits.max_value_length => return Err(ParseError::ValueTooLong(name)),
+        None => match String::from_utf8(data.to_vec()) {
+            Ok(value) => form.fields.entry(name).or_default().push(value),
+            Err(_) => return Err(ParseError::InvalidUtf8(name)),
+        },
+    }
+    Ok(())
+}
+
+/// Split a header value like `form-data; name="a"` at each `;` which is not
+/// in a quoted string, refer to RFC 2183 and RFC 7578.
+fn split_params(value: &str) -> Vec<&str> {
+    let mut params = Vec::new();
+    let mut start = 0;
+    let mut quoted = false;
+    let mut escaped = false;
+    for (i, c) in value.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if quoted && c == '\\' {
+            escaped = true;
+        } else if c == '"' {
+            quoted = !quoted;
+        } else if c == ';' && !quoted {
+            params.push(&value[start..i]);
+            start = i + 1;
+        }
+    }
+    params.push(&value[start..]);
+    params
+}
+
+fn split_param(param: &str) -> Option<(&str, Cow<'_, str>)> {
+    let i = param.find('=')?;
+    Some((param[..i].trim(), unquote(param[i + 1..].trim())))
+}
+
+/// Strip the quotes of a quoted string and unescape the characters after a
+/// backslash in it, a token is kept as is.
+fn unquote(value: &str) -> Cow<'_, str> {
+    let inner = match value.strip_prefix('"').and_then(|value| value.strip_suffix('"')) {
+        Some(inner) => inner,
+        None => return Cow::Borrowed(value),
+    };
+    if !inner.contains('\\') {
+        return Cow::Borrowed(inner);
+    }
+    let mut unescaped = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.extend(chars.next()),
+            c => unescaped.push(c),
+        }
+    }
+    Cow::Owned(unescaped)
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+/// A field type to represent an uploaded file.
+///
+/// It checks the size, the declared content type, the content type sniffed
+/// from the first bytes, and the extension of the file name. Each check is
+/// skipped unless configured.
+///
+/// # Examples
+///
+/// ```
+/// # use form_checker::{Validator, Checker, File};
+/// let mut validator = Validator::new();
+/// validator.check(Checker::new("avatar", "头像", File::new()
+///                              .max_size(1024 * 1024)
+///                              .content_types(&["image/png", "image/jpeg"])
+///                              .extensions(&["png", "jpg", "jpeg"])
+///                              .sniff(true)));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct File {
+    max_size: Option<usize>,
+    content_types: Vec<String>,
+    extensions: Vec<String>,
+    sniff: bool,
+}
+
+impl File {
+    /// Construct a `File` which accepts any file.
+    pub fn new() -> File {
+        File::default()
+    }
+
+    /// Limit the size of the file in bytes.
+    pub fn max_size(mut self, max_size: usize) -> File {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Limit the declared content type, eg. `image/png`.
+    pub fn content_types(mut self, content_types: &[&str]) -> File {
+        self.content_types = content_types.iter().map(|t| t.to_ascii_lowercase()).collect();
+        self
+    }
+
+    /// Limit the extension of the file name, eg. `png`.
+    pub fn extensions(mut self, extensions: &[&str]) -> File {
+        self.extensions = extensions.iter().map(|e| e.to_ascii_lowercase()).collect();
+        self
+    }
+
+    /// True means the content type is also told from the first bytes of the
+    /// file, which must be a known type, agree with the declared one and be
+    /// one of `content_types` if given.
+    pub fn sniff(mut self, sniff: bool) -> File {
+        self.sniff = sniff;
+        self
+    }
+
+    fn is_acceptable(&self, file: &UploadedFile) -> bool {
+        let declared = file.content_type.as_ref()
+            .map(|t| t.split(';').next().unwrap_or("").trim().to_ascii_lowercase());
+
+        if !self.content_types.is_empty() {
+            match declared {
+                Some(ref declared) if self.content_types.contains(declared) => {},
+                _ => return false,
+            }
+        }
+
+        if self.sniff {
+            let sniffed = match sniff(&file.data) {
+                Some(sniffed) => sniffed,
+                None => return false,
+            };
+            if declared.as_ref().is_some_and(|declared| declared != sniffed) {
+                return false;
+            }
+            if !self.content_types.is_empty() && !self.content_types.iter().any(|t| t == sniffed) {
+                return false;
+            }
+        }
+
+        if !self.extensions.is_empty() {
+            let extension = file.filename.as_ref()
+                .and_then(|name| name.rsplit_once('.'))
+                .map(|(_, extension)| extension.to_ascii_lowercase());
+            match extension {
+                Some(ref extension) if self.extensions.contains(extension) => {},
+                _ => return false,
+            }
+        }
+
+        true
+    }
+}
+
+impl FieldType for File {
+    fn from_str(&self, field_name: &str, field_title: &str, value: &str) -> Result<FieldValue, Message> {
+        Err(Message::some(MessageKind::Format,
+                          field_name,
+                          field_title,
+                          Some(value.to_string()),
+                          Vec::new()))
+    }
+
+    fn from_raw(&self, field_name: &str, field_title: &str, value: &RawValue) -> Result<FieldValue, Message> {
+        let file = match *value {
+            RawValue::File(file) => file,
+            _ => return self.from_str(field_name, field_title, &value.to_string()),
+        };
+        if let Some(max_size) = self.max_size {
+            if file.data.len() > max_size {
+                return Err(Message::some(MessageKind::MaxLen,
+                                         field_name,
+                                         field_title,
+                                         Some(value.to_string()),
+                                         vec![max_size.to_string()]));
+            }
+        }
+        if !self.is_acceptable(file) {
+            return Err(Message::some(MessageKind::Format,
+                                     field_name,
+                                     field_title,
+                                     Some(value.to_string()),
+                                     Vec::new()));
+        }
+        Ok(FieldValue::File(file.clone()))
+    }
+}
+
+/// Tell the content type of some well-known formats from their magic bytes.
+fn sniff(data: &[u8]) -> Option<&'static str> {
+    const SIGNATURES: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF87a", "image/gif"),
+        (b"GIF89a", "image/gif"),
+        (b"BM", "image/bmp"),
+        (b"%PDF-", "application/pdf"),
+        (b"PK\x03\x04", "application/zip"),
+    ];
+    if data.len() >= 12 && &data[..4] == b"RIFF" && &data[8..12] == b"WEBP" {
+        return Some("image/webp");
+    }
+    SIGNATURES.iter()
+        .find(|&&(magic, _)| data.starts_with(magic))
+        .map(|&(_, content_type)| content_type)
+}
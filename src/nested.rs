@@ -0,0 +1,267 @@
+//! Nested params with bracketed field names, like `user[address][city]` and
+//! `items[0][qty]`, and the `Group` checker for them.
+
+use std::collections::{BTreeMap, HashMap};
+
+use {CheckResult, Checkable, CheckerOption, EmptyPolicy, FieldValue, Message, MessageKind, Source, ValidData};
+
+/// Nested params under a repeated field, each with the key it was submitted
+/// with, eg. `2` for `items[2][...]`.
+pub type Children<'a> = Vec<(String, Box<dyn Source + 'a>)>;
+
+/// Split a bracketed key into its segments, eg. `items[0][qty]` into `items`,
+/// `0` and `qty`.
+///
+/// A key which is not well bracketed is a single segment.
+///
+/// # Examples
+///
+/// ```
+/// # use form_checker::split_key;
+/// assert_eq!(split_key("user[address][city]"), vec!["user", "address", "city"]);
+/// assert_eq!(split_key("tags[]"), vec!["tags", ""]);
+/// assert_eq!(split_key("a[b"), vec!["a[b"]);
+/// ```
+pub fn split_key(key: &str) -> Vec<&str> {
+    let first = match key.find('[') {
+        Some(0) | None => return vec![key],
+        Some(i) => i,
+    };
+    let mut segments = vec![&key[..first]];
+    let mut rest = &key[first..];
+    while !rest.is_empty() {
+        let end = match rest.find(']') {
+            Some(end) if rest.starts_with('[') => end,
+            _ => return vec![key],
+        };
+        segments.push(&rest[1..end]);
+        rest = &rest[end + 1..];
+    }
+    segments
+}
+
+/// Join segments back into a bracketed key, the reverse of `split_key`.
+pub fn join_key(segments: &[&str]) -> String {
+    let mut key = segments[0].to_string();
+    for segment in &segments[1..] {
+        key.push('[');
+        key.push_str(segment);
+        key.push(']');
+    }
+    key
+}
+
+/// The full path of a field inside the group at `path`, empty at the top level.
+pub(crate) fn join_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}[{}]", path, name)
+    }
+}
+
+/// The values of `name`, including those submitted as `name[]`.
+pub(crate) fn lookup<'a, V>(map: &'a HashMap<String, Vec<V>>, name: &str) -> Option<Vec<&'a V>> {
+    let plain = map.get(name);
+    let bracketed = map.get(&format!("{}[]", name));
+    if plain.is_none() && bracketed.is_none() {
+        return None;
+    }
+    Some(plain.into_iter().chain(bracketed).flatten().collect())
+}
+
+/// The params under `name[...]`, with `name` stripped from their keys.
+pub(crate) fn child_map<V: Clone>(map: &HashMap<String, Vec<V>>, name: &str) -> HashMap<String, Vec<V>> {
+    let mut child: HashMap<String, Vec<V>> = HashMap::new();
+    for (key, values) in map {
+        let segments = split_key(key);
+        if segments.len() > 1 && segments[0] == name && !segments[1].is_empty() {
+            child.entry(join_key(&segments[1..])).or_default().extend(values.iter().cloned());
+        }
+    }
+    child
+}
+
+/// The params under `name[<index>][...]` by index, with `name[<index>]`
+/// stripped from their keys.
+pub(crate) fn item_maps<V: Clone>(map: &HashMap<String, Vec<V>>, name: &str) -> BTreeMap<usize, HashMap<String, Vec<V>>> {
+    let mut items: BTreeMap<usize, HashMap<String, Vec<V>>> = BTreeMap::new();
+    for (key, values) in map {
+        let segments = split_key(key);
+        if segments.len() > 2 && segments[0] == name {
+            if let Ok(index) = segments[1].parse::<usize>() {
+                items.entry(index).or_default()
+                    .entry(join_key(&segments[2..])).or_default()
+                    .extend(values.iter().cloned());
+            }
+        }
+    }
+    items
+}
+
+/// The checker for a group of nested fields, a sub-form.
+///
+/// The fields of a group named `user` are submitted as `user[name]`,
+/// `user[address][city]` and so on. A multiple group named `items` is
+/// submitted as `items[0][qty]`, `items[1][qty]` and so on.
+///
+/// The valid values and invalid messages of the nested fields are keyed by
+/// their full paths, eg. `items[2][qty]`. A group itself is valid when it is
+/// present, with the keys of its items as values if it is multiple.
+///
+/// # Examples
+///
+/// ```
+/// # use form_checker::{Validator, Checker, CheckerOption, Group, Rule, Str, I64};
+/// let mut params = std::collections::HashMap::new();
+/// params.insert("user[name]".to_string(), vec!["bob".to_string()]);
+/// params.insert("items[0][qty]".to_string(), vec!["1".to_string()]);
+/// params.insert("items[1][qty]".to_string(), vec!["0".to_string()]);
+///
+/// let mut validator = Validator::new();
+/// validator
+///     .check(Group::new("user", "用户")
+///            .check(Checker::new("name", "姓名", Str)))
+///     .check(Group::new("items", "明细")
+///            .set(CheckerOption::Multiple(true))
+///            .check(Checker::new("qty", "数量", I64)
+///                   .meet(Rule::Min(1))));
+/// let result = validator.evaluate(&params);
+/// assert_eq!(result.get_required("user[name]").as_str().unwrap(), "bob".to_string());
+/// assert_eq!(result.get_required("items[0][qty]").as_i64().unwrap(), 1);
+/// assert_eq!(result.get_error("items[1][qty]"), "数量不能小于1");
+/// ```
+pub struct Group {
+    field_name: String,
+    field_title: String,
+    checkers: Vec<Box<dyn Checkable>>,
+    optional: bool,
+    multiple: bool,
+}
+
+impl Group {
+    /// Construct a new `Group`, refer to `Checker::new`.
+    pub fn new(field_name: &str, field_title: &str) -> Group {
+        Group {
+            field_name: field_name.to_string(),
+            field_title: field_title.to_string(),
+            checkers: Vec::new(),
+            optional: false,
+            multiple: false,
+        }
+    }
+
+    /// Add a checker for a nested field to this group.
+    pub fn check<U: Checkable + 'static>(mut self, checker: U) -> Group {
+        self.checkers.push(Box::new(checker));
+        self
+    }
+
+    /// Set an option for this group.
+    ///
+    /// `CheckerOption::Optional` and `CheckerOption::Multiple` are supported,
+    /// the latter meaning the group is repeated.
+    ///
+    /// # Panics
+    ///
+    /// Panics on any other option, set it on the nested checkers instead.
+    pub fn set(mut self, option: CheckerOption) -> Group {
+        match option {
+            CheckerOption::Optional(optional) => {
+                self.optional = optional;
+            },
+            CheckerOption::Multiple(multiple) => {
+                self.multiple = multiple;
+            },
+            _ => panic!("unsupported option for group `{}`, only Optional and Multiple can be set",
+                        self.field_name),
+        }
+        self
+    }
+
+    fn missing(&self) -> CheckResult {
+        if !self.optional {
+            return Err(vec![Message::some(MessageKind::Blank,
+                                          &self.field_name,
+                                          &self.field_title,
+                                          None, Vec::new())]);
+        }
+        Ok(None)
+    }
+}
+
+impl Checkable for Group {
+    fn check(&self, params: &dyn Source) -> CheckResult {
+        if self.multiple {
+            match params.children(&self.field_name) {
+                Some(items) => Ok(Some(items.into_iter().map(|(key, _)| FieldValue::Str(key)).collect())),
+                None => self.missing(),
+            }
+        } else {
+            match params.child(&self.field_name) {
+                Some(_) => Ok(Some(Vec::new())),
+                None => self.missing(),
+            }
+        }
+    }
+
+    fn get_name(&self) -> String {
+        self.field_name.clone()
+    }
+
+    fn check_default(&self) -> Result<(), Message> {
+        self.checkers.iter().try_for_each(|checker| checker.check_default())
+    }
+
+    fn inherit_empty_policy(&mut self, policy: EmptyPolicy) {
+        for checker in &mut self.checkers {
+            checker.inherit_empty_policy(policy);
+        }
+    }
+
+    fn get_title(&self) -> String {
+        self.field_title.clone()
+    }
+
+    fn check_cross_fields(&self, _siblings: &[Box<dyn Checkable>], path: &str, data: &ValidData, out: &mut Vec<(String, Message)>) {
+        let path = join_path(path, &self.field_name);
+        let items = match data.get(&path) {
+            Some(Some(keys)) if self.multiple => keys.iter().map(|key| join_path(&path, &key.to_string())).collect(),
+            Some(Some(_)) => vec![path],
+            _ => return,
+        };
+        for item in items {
+            for checker in &self.checkers {
+                checker.check_cross_fields(&self.checkers, &item, data, out);
+            }
+        }
+    }
+
+    fn check_fields(&self, params: &dyn Source, _siblings: &[Box<dyn Checkable>], path: &str, out: &mut Vec<(String, CheckResult)>) {
+        let path = join_path(path, &self.field_name);
+        if self.multiple {
+            match params.children(&self.field_name) {
+                Some(items) => {
+                    out.push((path.clone(), Ok(Some(items.iter().map(|(key, _)| FieldValue::Str(key.clone())).collect()))));
+                    for (key, item) in items {
+                        let item_path = join_path(&path, &key);
+                        for checker in &self.checkers {
+                            checker.check_fields(&*item, &self.checkers, &item_path, out);
+                        }
+                    }
+                },
+                None => out.push((path, self.missing())),
+            }
+        } else {
+            match params.child(&self.field_name) {
+                Some(child) => {
+                    out.push((path.clone(), Ok(Some(Vec::new()))));
+                    for checker in &self.checkers {
+                        checker.check_fields(&*child, &self.checkers, &path, out);
+                    }
+                },
+                None => out.push((path, self.missing())),
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Decoding of URL-encoded query strings and form bodies into params.
+
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+/// Limits applied when decoding a URL-encoded or multipart input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Limits {
+    /// The maximum number of key/value pairs, default 1000.
+    pub max_pairs: usize,
+    /// The maximum length of the raw input in bytes, default 1MB.
+    pub max_length: usize,
+    /// The maximum length of a decoded key in bytes, default 256.
+    pub max_key_length: usize,
+    /// The maximum length of a decoded value in bytes, default 64KB. It
+    /// doesn't apply to uploaded files.
+    pub max_value_length: usize,
+    /// The maximum number of values, or files, under one key, default 100.
+    pub max_values: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits {
+            max_pairs: 1000,
+            max_length: 1024 * 1024,
+            max_key_length: 256,
+            max_value_length: 64 * 1024,
+            max_values: 100,
+        }
+    }
+}
+
+/// The error returned when a URL-encoded or multipart input can't be decoded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseError {
+    /// The input is longer than `Limits::max_length`.
+    TooLong,
+    /// The input has more pairs, or parts, than `Limits::max_pairs`.
+    TooManyPairs,
+    /// A key is longer than `Limits::max_key_length`.
+    KeyTooLong,
+    /// A value is longer than `Limits::max_value_length`, with the key it
+    /// belongs to.
+    ValueTooLong(String),
+    /// A key has more values, or files, than `Limits::max_values`, with the key.
+    TooManyValues(String),
+    /// A key or a value is not valid UTF-8 once decoded, with the (lossily
+    /// decoded) key it belongs to.
+    InvalidUtf8(String),
+    /// A multipart input is broken, with the reason.
+    Malformed(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::TooLong => write!(f, "input is too long"),
+            ParseError::TooManyPairs => write!(f, "input has too many pairs"),
+            ParseError::KeyTooLong => write!(f, "input has a key which is too long"),
+            ParseError::ValueTooLong(ref key) => write!(f, "field `{}` is too long", key),
+            ParseError::TooManyValues(ref key) => write!(f, "field `{}` has too many values", key),
+            ParseError::InvalidUtf8(ref key) => write!(f, "field `{}` is not valid UTF-8", key),
+            ParseError::Malformed(ref reason) => write!(f, "malformed input: {}", reason),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Decode a query string, such as `a=1&b=2&b=3`, into params.
+///
+/// A leading `?` is ignored. Refer to `parse_urlencoded`.
+pub fn parse_query(query: &str, limits: &Limits) -> Result<HashMap<String, Vec<String>>, ParseError> {
+    let query = query.strip_prefix('?').unwrap_or(query);
+    parse_urlencoded(query.as_bytes(), limits)
+}
+
+/// Decode an `application/x-www-form-urlencoded` body into params.
+///
+/// Values of a repeated key are kept in order, which is what
+/// `CheckerOption::Multiple` expects. `+` is decoded as a space, and a `%`
+/// not followed by two hex digits is kept as is.
+///
+/// # Examples
+///
+/// ```
+/// # use form_checker::{parse_urlencoded, Limits};
+/// let params = parse_urlencoded(b"name=%E5%BC%A0+san&tag=a&tag=b", &Limits::default()).unwrap();
+/// assert_eq!(params["name"], vec!["张 san".to_string()]);
+/// assert_eq!(params["tag"], vec!["a".to_string(), "b".to_string()]);
+/// ```
+pub fn parse_urlencoded(input: &[u8], limits: &Limits) -> Result<HashMap<String, Vec<String>>, ParseError> {
+    if input.len() > limits.max_length {
+        return Err(ParseError::TooLong);
+    }
+
+    let mut params: HashMap<String, Vec<String>> = HashMap::new();
+    let mut pairs = 0;
+    for pair in input.split(|&b| b == b'&').filter(|pair| !pair.is_empty()) {
+        pairs += 1;
+        if pairs > limits.max_pairs {
+            return Err(ParseError::TooManyPairs);
+        }
+
+        let (key, value) = match pair.iter().position(|&b| b == b'=') {
+            Some(i) => (&pair[..i], &pair[i + 1..]),
+            None => (pair, &b""[..]),
+        };
+        let key = decode(key);
+        if key.len() > limits.max_key_length {
+            return Err(ParseError::KeyTooLong);
+        }
+        let value = decode(value);
+        let key = match String::from_utf8(key) {
+            Ok(key) => key,
+            Err(e) => return Err(ParseError::InvalidUtf8(String::from_utf8_lossy(e.as_bytes()).into_owned())),
+        };
+        if value.len() > limits.max_value_length {
+            return Err(ParseError::ValueTooLong(key));
+        }
+        let value = match String::from_utf8(value) {
+            Ok(value) => value,
+            Err(_) => return Err(ParseError::InvalidUtf8(key)),
+        };
+        if params.get(&key).map_or(0, Vec::len) >= limits.max_values {
+            return Err(ParseError::TooManyValues(key));
+        }
+        params.entry(key).or_default().push(value);
+    }
+    Ok(params)
+}
+
+fn decode(input: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        let escaped = if input[i] == b'%' && i + 2 < input.len() {
+            hex(input[i + 1]).and_then(|hi| hex(input[i + 2]).map(|lo| hi * 16 + lo))
+        } else {
+            None
+        };
+        match escaped {
+            Some(b) => {
+                out.push(b);
+                i += 3;
+            },
+            None => {
+                out.push(if input[i] == b'+' { b' ' } else { input[i] });
+                i += 1;
+            },
+        }
+    }
+    out
+}
+
+fn hex(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "json")]
+
+extern crate form_checker;
+#[macro_use]
+extern crate serde_json;
+
+use form_checker::{Validator, Checker, CheckerOption, Group, Rule, Str, I64, F64, Decimal, Bool, FieldValue, GetError};
+
+#[test]
+fn validate_json() {
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("name", "姓名", Str))
+        .check(Checker::new("age", "年龄", I64)
+               .meet(Rule::Min(18)))
+        .check(Checker::new("nickname", "昵称", Str)
+               .set(CheckerOption::Optional(true)))
+        .check(Checker::new("subscribe", "订阅", Bool::new()))
+        .check(Checker::new("tag", "标签", I64)
+               .set(CheckerOption::Multiple(true)))
+        .check(Group::new("address", "地址")
+               .check(Checker::new("city", "城市", Str)))
+        .check(Group::new("items", "明细")
+               .set(CheckerOption::Multiple(true))
+               .check(Checker::new("qty", "数量", I64)));
+
+    let result = validator.validate_json(&json!({
+        "name": "bob",
+        "age": 20,
+        "nickname": null,
+        "subscribe": true,
+        "tag": [1, 2],
+        "address": {"city": "上海"},
+        "items": [{"qty": 1}, {"qty": 2}],
+    }));
+    assert!(result.is_valid());
+    assert_eq!(result.try_get_str("name"), Ok("bob".to_string()));
+    assert_eq!(result.try_get_i64("age"), Ok(20));
+    assert_eq!(result.try_get_optional("nickname"), Ok(None));
+    assert_eq!(result.try_get_bool("subscribe"), Ok(true));
+    assert_eq!(result.get_required_multiple("tag"), vec![FieldValue::I64(1), FieldValue::I64(2)]);
+    assert_eq!(result.try_get_str("address[city]"), Ok("上海".to_string()));
+    assert_eq!(result.try_get_i64("items[1][qty]"), Ok(2));
+
+    ////////////////////////////////////////////////
+
+    let result = validator.validate_json(&json!({
+        "name": 1,
+        "age": 20.5,
+        "subscribe": 1.5,
+        "tag": [1, 2.5, true],
+        "address": "上海",
+        "items": [{"qty": {"n": 1}}],
+    }));
+    assert!(!result.is_valid());
+    assert_eq!(result.get_error("name"), "姓名格式不正确");
+    assert_eq!(result.get_error("age"), "年龄格式不正确");
+    assert_eq!(result.get_error("subscribe"), "订阅格式不正确");
+    assert_eq!(result.get_error("tag"), "标签格式不正确");
+    assert_eq!(result.get_field_errors("tag")[0].index, Some(1));
+    assert_eq!(result.get_error("address"), "地址不能为空");
+    assert_eq!(result.get_error("items[0][qty]"), "数量格式不正确");
+
+    ////////////////////////////////////////////////
+
+    let result = validator.validate_json(&json!([1, 2]));
+    assert_eq!(result.get_error("name"), "姓名不能为空");
+    assert_eq!(result.try_get_str("name"), Err(GetError::Invalid("name".to_string())));
+}
+
+#[test]
+fn validate_json_huge_number() {
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("mass", "质量", F64::new()))
+        .check(Checker::new("count", "数量", I64))
+        .check(Checker::new("amount", "金额", Decimal::new()));
+
+    let result = validator.validate_json(&json!({"mass": 1e300, "count": 1e300, "amount": 1e300}));
+    assert_eq!(result.try_get_f64("mass"), Ok(1e300));
+    assert_eq!(result.get_error("count"), "数量格式不正确");
+    assert_eq!(result.get_error("amount"), "金额格式不正确");
+}
@@ -1,7 +1,12 @@
 extern crate form_checker;
+extern crate chrono;
 
 use std::collections::HashMap;
-use form_checker::{Validator, Checker, Rule, MessageRenderer, CheckerOption, Str, I64, ChinaMobile, Email, SomeMessage, MessageKind, Message, FieldValue, FieldType};
+use std::sync::Arc;
+use std::thread;
+use form_checker::regex::Regex;
+use chrono::{Duration, FixedOffset, Local, Months, NaiveDate, NaiveTime, TimeZone};
+use form_checker::{Validator, Checker, Checkable, CheckResult, Source, Rule, MessageRenderer, CheckerOption, Str, I64, ChinaMobile, Email, SomeMessage, MessageKind, Message, FieldValue, FieldType, CustomRule, RuleContext, InvalidMessage, GetError, F64, Decimal, DecimalValue, Bool, Date, Time, DateTime, Choice, LengthMode, EmptyPolicy, Filter, Trim, Lowercase, CollapseWhitespace, Nfc, Nfkc, StripControl, HalfWidth, Format, Limits, ParseError, File, Form, UploadedFile, Group, split_key, join_key};
 
 #[test]
 fn check_str() {
@@ -45,7 +50,7 @@ fn check_str_format() {
     let mut validator = Validator::new();
     validator
         .check(Checker::new("username", "username", Str)
-                     .meet(Rule::Format(r"l\dy")));
+                     .meet(Rule::Format(r"l\dy".into())));
 
     let mut params = HashMap::new();
     params.insert("username".to_string(), vec!["hellokitty".to_string()]);
@@ -62,6 +67,45 @@ fn check_str_format() {
     assert_eq!(validator.get_required("username").as_str().unwrap(), "l5y".to_string());
 }
 
+#[test]
+fn check_format_modes() {
+    assert!(Format::new(r"l\d(y").is_err());
+    assert!(Checker::new("code", "编码", Str).try_meet(Rule::Format(r"l\d(y".into())).is_err());
+    assert!(Checker::new("code", "编码", Str).try_meet(Rule::AnyOf(vec![Rule::Format("(".into())])).is_err());
+    assert!(Checker::new("code", "编码", Str).try_meet(Rule::Format(r"l\dy".into())).is_ok());
+
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("partial", "partial", Str)
+               .meet(Rule::Format(Format::new(&String::from(r"l\dy")).unwrap())))
+        .check(Checker::new("full", "full", Str)
+               .meet(Rule::Format(Format::full_match(r"l\dy").unwrap())))
+        .check(Checker::new("regex", "regex", Str)
+               .meet(Rule::Format(Format::from(Regex::new(r"l\dy").unwrap()).full())));
+
+    let mut params = HashMap::new();
+    params.insert("partial".to_string(), vec!["xl5yx".to_string()]);
+    params.insert("full".to_string(), vec!["xl5yx".to_string()]);
+    params.insert("regex".to_string(), vec!["xl5yx".to_string()]);
+    let result = validator.evaluate(&params);
+    assert!(result.try_get_required("partial").is_ok());
+    assert_eq!(result.get_error("full"), "full格式不正确");
+    assert_eq!(result.get_error("regex"), "regex格式不正确");
+
+    ////////////////////////////////////////////////
+
+    params.insert("full".to_string(), vec!["l5y".to_string()]);
+    params.insert("regex".to_string(), vec!["l5y".to_string()]);
+    assert!(validator.evaluate(&params).is_valid());
+}
+
+#[test]
+#[should_panic(expected = "invalid rule for field `username`: invalid format pattern")]
+fn check_invalid_format() {
+    Checker::new("username", "username", Str)
+        .meet(Rule::Format(r"l\d(y".into()));
+}
+
 struct EnglishMessageRenderer;
 impl MessageRenderer for EnglishMessageRenderer {
     fn render_message(&self, m: SomeMessage) -> String {
@@ -72,6 +116,35 @@ impl MessageRenderer for EnglishMessageRenderer {
             MessageKind::MinLen => format!("{title} can't be shorter than {rule}", title=m.title, rule=m.rule_values[0]),
             MessageKind::Blank => format!("{title} is missing", title=m.title),
             MessageKind::Format => format!("{title} is in wrong format", title=m.title),
+            MessageKind::Before => format!("{title} must be before {rule}", title=m.title, rule=m.rule_values[0]),
+            MessageKind::After => format!("{title} must be after {rule}", title=m.title, rule=m.rule_values[0]),
+            MessageKind::Future => format!("{title} can't be in the future", title=m.title),
+            MessageKind::Past => format!("{title} can't be in the past", title=m.title),
+            MessageKind::Within => format!("{title} must be within {count} {unit} from now", title=m.title, count=m.rule_values[0], unit=m.rule_values[1]),
+            MessageKind::MinAge => format!("{title} must be at least {rule} years ago", title=m.title, rule=m.rule_values[0]),
+            MessageKind::OneOf => format!("{title} must be one of {rule}", title=m.title, rule=m.rule_values.join(", ")),
+            MessageKind::NoneOf => format!("{title} can't be {rule}", title=m.title, rule=m.rule_values.join(", ")),
+            MessageKind::EqualsField => format!("{title} must match {other}", title=m.title, other=m.rule_values[0]),
+            MessageKind::NotEqualsField => format!("{title} can't be the same as {other}", title=m.title, other=m.rule_values[0]),
+            MessageKind::LessThanField => format!("{title} must be less than {other}", title=m.title, other=m.rule_values[0]),
+            MessageKind::LessOrEqualField => format!("{title} can't be more than {other}", title=m.title, other=m.rule_values[0]),
+            MessageKind::GreaterThanField => format!("{title} must be more than {other}", title=m.title, other=m.rule_values[0]),
+            MessageKind::GreaterOrEqualField => format!("{title} can't be less than {other}", title=m.title, other=m.rule_values[0]),
+            MessageKind::Excluded => format!("{title} must be empty", title=m.title),
+            MessageKind::MinItems => format!("pick at least {rule} {title}", title=m.title, rule=m.rule_values[0]),
+            MessageKind::MaxItems => format!("pick at most {rule} {title}", title=m.title, rule=m.rule_values[0]),
+            MessageKind::UniqueItems => format!("{title} has a duplicate {rule}", title=m.title, rule=m.rule_values[0]),
+            MessageKind::SortedItems => format!("{title} must be in order", title=m.title),
+            MessageKind::Not => format!("{title} is not allowed", title=m.title),
+            MessageKind::AnyOf => {
+                let causes: Vec<String> = m.causes.into_iter().map(|cause| self.render_cause(cause)).collect();
+                format!("{title} must meet one of: {causes}", title=m.title, causes=causes.join("; "))
+            },
+            MessageKind::AllOf => {
+                let causes: Vec<String> = m.causes.into_iter().map(|cause| self.render_cause(cause)).collect();
+                causes.join("; ")
+            },
+            _ => format!("{title} is invalid", title=m.title),
         }
     }
 }
@@ -82,7 +155,7 @@ fn other_message_lang() {
     let mut validator = Validator::with_message(EnglishMessageRenderer);
     validator
         .check(Checker::new("username", "username", Str)
-                     .meet(Rule::Format(r"l\dy")));
+                     .meet(Rule::Format(r"l\dy".into())));
 
     let mut params = HashMap::new();
     params.insert("username".to_string(), vec!["hellokitty".to_string()]);
@@ -166,7 +239,7 @@ fn check_i64() {
     let mut validator = Validator::new();
     validator
         .check(Checker::new("age", "age", I64)
-                     .meet(Rule::Format(r"\d{4}")));
+                     .meet(Rule::Format(r"\d{4}".into())));
 
     let mut params = HashMap::new();
     params.insert("age".to_string(), vec!["3456".to_string()]);
@@ -184,6 +257,927 @@ fn check_i64() {
 
 }
 
+#[test]
+fn check_f64() {
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("price", "价格", F64::new())
+               .meet(Rule::Finite)
+               .meet(Rule::Scale(2))
+               .meet(Rule::MaxF64(99.5))
+               .meet(Rule::Min(0)))
+        .check(Checker::new("mass", "质量", F64::new().exponent(true))
+               .set(CheckerOption::Optional(true))
+               .meet(Rule::Scale(4)));
+
+    let mut params = HashMap::new();
+    params.insert("price".to_string(), vec!["9.99".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_f64("price"), Ok(9.99));
+    params.insert("price".to_string(), vec!["+1.5".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_f64("price"), Ok(1.5));
+    params.insert("price".to_string(), vec![".5".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_f64("price"), Ok(0.5));
+    params.insert("price".to_string(), vec!["7".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_f64("price"), Ok(7.0));
+    params.insert("price".to_string(), vec!["99.5".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_f64("price"), Ok(99.5));
+    params.insert("price".to_string(), vec!["99.51".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("price"), "价格不能大于99.5");
+    params.insert("price".to_string(), vec!["-0.5".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("price"), "价格不能小于0");
+    params.insert("price".to_string(), vec!["1.999".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("price"), "价格格式不正确");
+    params.insert("price".to_string(), vec!["1e1".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("price"), "价格格式不正确");
+    params.insert("price".to_string(), vec!["NaN".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("price"), "价格格式不正确");
+    params.insert("price".to_string(), vec!["inf".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("price"), "价格格式不正确");
+    params.insert("price".to_string(), vec!["1.2.3".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("price"), "价格格式不正确");
+    params.insert("price".to_string(), vec!["-".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("price"), "价格格式不正确");
+    params.insert("price".to_string(), vec!["".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("price"), "价格格式不正确");
+
+    ////////////////////////////////////////////////
+
+    let mut params = HashMap::new();
+    params.insert("price".to_string(), vec!["1".to_string()]);
+    params.insert("mass".to_string(), vec!["1.5e3".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_f64("mass"), Ok(1500.0));
+    params.insert("mass".to_string(), vec!["1.5E-3".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_f64("mass"), Ok(0.0015));
+    params.insert("mass".to_string(), vec!["1.5e-3".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_f64("mass"), Ok(0.0015));
+    params.insert("mass".to_string(), vec!["1.5e-4".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("mass"), "质量格式不正确");
+    params.insert("mass".to_string(), vec!["1e".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("mass"), "质量格式不正确");
+    params.insert("mass".to_string(), vec!["e5".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("mass"), "质量格式不正确");
+
+    let mut validator = Validator::new();
+    validator.check(Checker::new("ratio", "比例", F64::new()));
+    let mut params = HashMap::new();
+    params.insert("ratio".to_string(), vec!["-Infinity".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_f64("ratio"), Ok(f64::NEG_INFINITY));
+
+    let mut validator = Validator::new();
+    validator.check(Checker::new("ratio", "比例", F64::new()).meet(Rule::Max(1)));
+    params.insert("ratio".to_string(), vec!["1.0".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_f64("ratio"), Ok(1.0));
+    params.insert("ratio".to_string(), vec!["1.5".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("ratio"), "比例不能大于1");
+}
+
+#[test]
+fn check_decimal() {
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("amount", "金额", Decimal::new().currency("$").thousands(','))
+               .meet(Rule::Scale(2))
+               .meet(Rule::Precision(8))
+               .meet(Rule::MinDecimal(DecimalValue::new(-5, 1)))
+               .meet(Rule::Max(100000)));
+
+    let mut params = HashMap::new();
+    params.insert("amount".to_string(), vec!["12".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_decimal("amount"), Ok(DecimalValue::new(12, 0)));
+    params.insert("amount".to_string(), vec!["0.10".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_decimal("amount"), Ok(DecimalValue::new(1, 1)));
+    params.insert("amount".to_string(), vec!["$1,234.56".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_decimal("amount"), Ok(DecimalValue::new(123456, 2)));
+    params.insert("amount".to_string(), vec!["-$0.5".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_decimal("amount"), Ok(DecimalValue::new(-5, 1)));
+    params.insert("amount".to_string(), vec!["$-0.5".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_decimal("amount"), Ok(DecimalValue::new(-5, 1)));
+    params.insert("amount".to_string(), vec!["1234.56$".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_decimal("amount"), Ok(DecimalValue::new(123456, 2)));
+    params.insert("amount".to_string(), vec!["100000.00".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_decimal("amount"), Ok(DecimalValue::new(100000, 0)));
+    params.insert("amount".to_string(), vec!["100000.01".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额不能大于100000");
+    params.insert("amount".to_string(), vec!["-0.51".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额不能小于-0.5");
+    params.insert("amount".to_string(), vec!["1.005".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+    params.insert("amount".to_string(), vec!["$1,23.00".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+    params.insert("amount".to_string(), vec!["1,2345".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+    params.insert("amount".to_string(), vec![",123".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+    params.insert("amount".to_string(), vec!["-$-1".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+    params.insert("amount".to_string(), vec!["€1".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+    params.insert("amount".to_string(), vec!["1.".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+    params.insert("amount".to_string(), vec![".5".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+    params.insert("amount".to_string(), vec!["1e3".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+    params.insert("amount".to_string(), vec!["".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+
+    ////////////////////////////////////////////////
+
+    let mut validator = Validator::new();
+    validator.check(Checker::new("amount", "金额", Decimal::new()).meet(Rule::Precision(4)));
+    let mut params = HashMap::new();
+    params.insert("amount".to_string(), vec!["99.99".to_string()]);
+    assert!(validator.evaluate(&params).is_valid());
+    params.insert("amount".to_string(), vec!["100.00".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+    params.insert("amount".to_string(), vec!["1,000".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+
+    let mut validator = Validator::new();
+    validator.check(Checker::new("amount", "金额", Decimal::new()).meet(Rule::Scale(2)).meet(Rule::Precision(2)));
+    params.insert("amount".to_string(), vec!["-0.05".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_decimal("amount"), Ok(DecimalValue::new(-5, 2)));
+    params.insert("amount".to_string(), vec!["-0.051".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+    params.insert("amount".to_string(), vec!["-10.5".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+
+    let mut validator = Validator::new();
+    validator.check(Checker::new("amount", "金额", Decimal::new()));
+    params.insert("amount".to_string(), vec!["9".repeat(38)]);
+    assert_eq!(validator.evaluate(&params).try_get_decimal("amount").unwrap().precision(), 38);
+    params.insert("amount".to_string(), vec![format!("-0.{}", "9".repeat(38))]);
+    assert_eq!(validator.evaluate(&params).try_get_decimal("amount").unwrap().scale(), 38);
+    params.insert("amount".to_string(), vec!["9".repeat(39)]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+    params.insert("amount".to_string(), vec![format!("0.{}", "1".repeat(39))]);
+    assert_eq!(validator.evaluate(&params).get_error("amount"), "金额格式不正确");
+
+    assert_eq!("0.05".parse::<DecimalValue>().unwrap().precision(), 2);
+    assert_eq!("-0.05".parse::<DecimalValue>().unwrap().precision(), 2);
+    assert_eq!("-0.05".parse::<DecimalValue>().unwrap().scale(), 2);
+    assert_eq!("-0".parse::<DecimalValue>().unwrap().to_string(), "0");
+    assert_eq!("0.00".parse::<DecimalValue>().unwrap().to_string(), "0.00");
+    assert!("1".repeat(39).parse::<DecimalValue>().is_err());
+    assert!(DecimalValue::new(i128::MAX, 0) > DecimalValue::new(1, 30));
+    assert!(DecimalValue::new(-1, 0) < DecimalValue::new(-1, 38));
+}
+
+#[test]
+fn check_bool() {
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("subscribe", "订阅", Bool::new()))
+        .check(Checker::new("agree", "同意", Bool::new())
+               .meet(Rule::Lambda(Box::new(|v| v.as_bool().unwrap()),
+                                  Some(Box::new(|_, title, _| format!("请{}条款", title))))))
+        .check(Checker::new("remember", "记住", Bool::new().truthy(&["Y"]).falsy(&["N", ""]))
+               .set(CheckerOption::Optional(true)));
+
+    let mut params = HashMap::new();
+    params.insert("agree".to_string(), vec!["on".to_string()]);
+    params.insert("subscribe".to_string(), vec!["on".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_bool("subscribe"), Ok(true));
+    params.insert("subscribe".to_string(), vec!["TRUE".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_bool("subscribe"), Ok(true));
+    params.insert("subscribe".to_string(), vec!["1".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_bool("subscribe"), Ok(true));
+    params.insert("subscribe".to_string(), vec!["Yes".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_bool("subscribe"), Ok(true));
+    params.insert("subscribe".to_string(), vec!["off".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_bool("subscribe"), Ok(false));
+    params.insert("subscribe".to_string(), vec!["false".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_bool("subscribe"), Ok(false));
+    params.insert("subscribe".to_string(), vec!["0".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_bool("subscribe"), Ok(false));
+    params.insert("subscribe".to_string(), vec!["no".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_bool("subscribe"), Ok(false));
+    params.insert("subscribe".to_string(), vec!["".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("subscribe"), "订阅格式不正确");
+    params.insert("subscribe".to_string(), vec!["2".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("subscribe"), "订阅格式不正确");
+
+    ////////////////////////////////////////////////
+
+    let mut params = HashMap::new();
+    params.insert("agree".to_string(), vec!["on".to_string()]);
+    let result = validator.evaluate(&params);
+    assert!(result.is_valid());
+    assert_eq!(result.try_get_bool("subscribe"), Ok(false));
+    assert_eq!(result.try_get_optional("remember"), Ok(None));
+
+    params.insert("remember".to_string(), vec!["".to_string()]);
+    assert_eq!(validator.evaluate(&params).try_get_bool("remember"), Ok(false));
+    params.insert("remember".to_string(), vec!["on".to_string()]);
+    assert_eq!(validator.evaluate(&params).get_error("remember"), "记住格式不正确");
+
+    params.remove("agree");
+    assert_eq!(validator.evaluate(&params).get_error("agree"), "请同意条款");
+}
+
+#[test]
+fn check_date() {
+    let ymd = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("birthday", "生日", Date::new())
+               .meet(Rule::After(FieldValue::Date(ymd(1900, 1, 1))))
+               .meet(Rule::NotInFuture)
+               .meet(Rule::MinAge(18)))
+        .check(Checker::new("expiry", "有效期", Date::new().format("%d/%m/%Y"))
+               .meet(Rule::NotInPast)
+               .meet(Rule::Before(FieldValue::Date(ymd(2100, 1, 1)))))
+        .check(Checker::new("slot", "时段", Time::new())
+               .meet(Rule::After(FieldValue::Time(NaiveTime::from_hms_opt(9, 0, 0).unwrap())))
+               .meet(Rule::Before(FieldValue::Time(NaiveTime::from_hms_opt(18, 0, 0).unwrap()))));
+
+    // Keep the dates years away from the boundaries, so that the results
+    // don't depend on the time zone or on the test running around midnight.
+    let today = Local::now().date_naive();
+    let adult = today.checked_sub_months(Months::new(19 * 12)).unwrap();
+    let minor = today.checked_sub_months(Months::new(17 * 12)).unwrap();
+    let expiry = today.checked_add_months(Months::new(10 * 12)).unwrap();
+    let valid = |birthday: &str, expiry: &str, slot: &str| {
+        let mut params = HashMap::new();
+        params.insert("birthday".to_string(), vec![birthday.to_string()]);
+        params.insert("expiry".to_string(), vec![expiry.to_string()]);
+        params.insert("slot".to_string(), vec![slot.to_string()]);
+        validator.evaluate(&params)
+    };
+
+    let result = valid(&adult.to_string(), &expiry.format("%d/%m/%Y").to_string(), "09:30");
+    assert!(result.is_valid());
+    assert_eq!(result.try_get_date("birthday"), Ok(adult));
+    assert_eq!(result.try_get_date("expiry"), Ok(expiry));
+    assert_eq!(result.try_get_time("slot"), Ok(NaiveTime::from_hms_opt(9, 30, 0).unwrap()));
+    assert_eq!(valid("2000-01-01", "31/12/2099", "17:59:59.5").try_get_time("slot"),
+               Ok(NaiveTime::from_hms_milli_opt(17, 59, 59, 500).unwrap()));
+
+    let result = valid(&minor.to_string(), "01/01/2000", "18:00");
+    assert_eq!(result.get_error("birthday"), "生日须满18周岁");
+    assert_eq!(result.get_error("expiry"), "有效期不能早于现在");
+    assert_eq!(result.get_error("slot"), "时段必须早于18:00:00");
+
+    let result = valid(&expiry.to_string(), "01/01/2100", "09:00");
+    assert_eq!(result.get_error("birthday"), "生日不能晚于现在");
+    assert_eq!(result.get_error("expiry"), "有效期必须早于2100-01-01");
+    assert_eq!(result.get_error("slot"), "时段必须晚于09:00:00");
+
+    let result = valid("1900-01-01", "2030-01-01", "9点");
+    assert_eq!(result.get_error("birthday"), "生日必须晚于1900-01-01");
+    assert_eq!(result.get_error("expiry"), "有效期格式不正确");
+    assert_eq!(result.get_error("slot"), "时段格式不正确");
+    assert_eq!(valid("2000-02-30", "", "").get_error("birthday"), "生日格式不正确");
+
+    // A date rule can't apply to a value or a bound of another kind.
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("birthday", "生日", Date::new())
+               .meet(Rule::Before(FieldValue::DateTime(FixedOffset::east_opt(0).unwrap()
+                                                       .with_ymd_and_hms(2100, 1, 1, 0, 0, 0).unwrap()))))
+        .check(Checker::new("name", "姓名", Str)
+               .meet(Rule::After(FieldValue::Date(ymd(1900, 1, 1)))))
+        .check(Checker::new("slot", "时段", Time::new())
+               .meet(Rule::NotInFuture));
+    let mut params = HashMap::new();
+    params.insert("birthday".to_string(), vec!["2000-01-01".to_string()]);
+    params.insert("name".to_string(), vec!["bob".to_string()]);
+    params.insert("slot".to_string(), vec!["09:00".to_string()]);
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("birthday"), "生日格式不正确");
+    assert_eq!(result.get_error("name"), "姓名格式不正确");
+    assert_eq!(result.get_error("slot"), "时段格式不正确");
+}
+
+#[test]
+fn check_datetime() {
+    let beijing = FixedOffset::east_opt(8 * 3600).unwrap();
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("start", "开始时间", DateTime::new().offset(beijing))
+               .meet(Rule::NotInPast)
+               .meet(Rule::Within(Duration::days(30))))
+        .check(Checker::new("logged", "记录时间", DateTime::new().format("%Y/%m/%d %H:%M"))
+               .set(CheckerOption::Optional(true))
+               .meet(Rule::Within(Duration::hours(2))));
+
+    let now = beijing.from_utc_datetime(&chrono::Utc::now().naive_utc());
+    let evaluate = |start: String, logged: Option<String>| {
+        let mut params = HashMap::new();
+        params.insert("start".to_string(), vec![start]);
+        if let Some(logged) = logged {
+            params.insert("logged".to_string(), vec![logged]);
+        }
+        validator.evaluate(&params)
+    };
+
+    let start = now + Duration::days(1);
+    let result = evaluate(start.format("%Y-%m-%dT%H:%M:%S").to_string(), None);
+    assert!(result.is_valid());
+    assert_eq!(result.try_get_datetime("start").unwrap().timestamp(), start.timestamp());
+
+    let result = evaluate((now + Duration::days(1)).to_rfc3339(),
+                          Some(now.naive_utc().format("%Y/%m/%d %H:%M").to_string()));
+    assert!(result.is_valid());
+    assert_eq!(result.try_get_datetime("start").unwrap(), (now + Duration::days(1)));
+
+    let result = evaluate((now + Duration::days(60)).to_rfc3339(),
+                          Some((now - Duration::days(1)).naive_utc().format("%Y/%m/%d %H:%M").to_string()));
+    assert_eq!(result.get_error("start"), "开始时间必须在距今30天以内");
+    assert_eq!(result.get_error("logged"), "记录时间必须在距今2小时以内");
+
+    let result = evaluate((now - Duration::days(1)).to_rfc3339(), Some("2017-03-09T09:30".to_string()));
+    assert_eq!(result.get_error("start"), "开始时间不能早于现在");
+    assert_eq!(result.get_error("logged"), "记录时间格式不正确");
+}
+
+#[test]
+fn check_choice() {
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("gender", "性别", Choice::new(&["male", "female"])
+                            .alias("1", "male")
+                            .alias("2", "female")))
+        .check(Checker::new("size", "尺码", Choice::new(&["S", "M", "L"])
+                            .ignore_case(true))
+               .set(CheckerOption::Multiple(true)))
+        .check(Checker::new("username", "用户名", Str)
+               .meet(Rule::NoneOf(vec!["admin".to_string(), "root".to_string()])))
+        .check(Checker::new("level", "等级", I64)
+               .set(CheckerOption::Optional(true))
+               .meet(Rule::OneOf(vec!["1".to_string(), "3".to_string(), "5".to_string()])));
+
+    let mut params = HashMap::new();
+    params.insert("gender".to_string(), vec!["2".to_string()]);
+    params.insert("size".to_string(), vec!["m".to_string(), "L".to_string()]);
+    params.insert("username".to_string(), vec!["bob".to_string()]);
+    params.insert("level".to_string(), vec!["3".to_string()]);
+    let result = validator.evaluate(&params);
+    assert!(result.is_valid());
+    assert_eq!(result.try_get_str("gender"), Ok("female".to_string()));
+    assert_eq!(result.get_required_multiple("size"),
+               vec![FieldValue::Str("M".to_string()), FieldValue::Str("L".to_string())]);
+    assert_eq!(result.try_get_i64("level"), Ok(3));
+
+    params.insert("gender".to_string(), vec!["Male".to_string()]);
+    params.insert("size".to_string(), vec!["XL".to_string()]);
+    params.insert("username".to_string(), vec!["root".to_string()]);
+    params.insert("level".to_string(), vec!["4".to_string()]);
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("gender"), "性别必须是male、female之一");
+    assert_eq!(result.get_error("size"), "尺码必须是S、M、L之一");
+    assert_eq!(result.get_error("username"), "用户名不能是admin、root");
+    assert_eq!(result.get_error("level"), "等级必须是1、3、5之一");
+
+    let mut validator = Validator::with_message(EnglishMessageRenderer);
+    validator.check(Checker::new("gender", "Gender", Choice::new(&["male", "female"])));
+    validator.validate(&params);
+    assert_eq!(validator.get_error("gender"), "Gender must be one of male, female");
+}
+
+#[test]
+fn check_length() {
+    assert_eq!(LengthMode::Bytes.count("张三"), 6);
+    assert_eq!(LengthMode::Chars.count("张三"), 2);
+    assert_eq!(LengthMode::Width.count("张三"), 4);
+    assert_eq!(LengthMode::Chars.count("e\u{301}"), 2);
+    assert_eq!(LengthMode::Graphemes.count("e\u{301}"), 1);
+    assert_eq!(LengthMode::Graphemes.count("👨\u{200d}👩\u{200d}👧"), 1);
+
+    let checker = |name: &str, mode: Option<LengthMode>, max: i64| {
+        let checker = Checker::new(name, "姓名", Str).meet(Rule::Max(max)).meet(Rule::Min(2));
+        match mode {
+            Some(mode) => checker.set(CheckerOption::Length(mode)),
+            None => checker,
+        }
+    };
+    let mut validator = Validator::new();
+    validator
+        .check(checker("default", None, 5))
+        .check(checker("bytes", Some(LengthMode::Bytes), 5))
+        .check(checker("graphemes", Some(LengthMode::Graphemes), 5))
+        .check(checker("width", Some(LengthMode::Width), 5));
+
+    let mut params = HashMap::new();
+    for name in &["default", "bytes", "graphemes", "width"] {
+        params.insert(name.to_string(), vec!["欧阳娜娜".to_string()]);
+    }
+    let result = validator.evaluate(&params);
+    assert!(result.try_get_str("default").is_ok());
+    assert_eq!(result.get_error("bytes"), "姓名长度不能大于5");
+    assert!(result.try_get_str("graphemes").is_ok());
+    assert_eq!(result.get_error("width"), "姓名长度不能大于5");
+
+    for name in &["default", "bytes", "graphemes", "width"] {
+        params.insert(name.to_string(), vec!["Zoe\u{308}".to_string()]);
+    }
+    let result = validator.evaluate(&params);
+    assert!(result.try_get_str("default").is_ok());
+    assert!(result.try_get_str("bytes").is_ok());
+    assert!(result.try_get_str("graphemes").is_ok());
+    assert!(result.try_get_str("width").is_ok());
+
+    for name in &["default", "bytes", "graphemes", "width"] {
+        params.insert(name.to_string(), vec!["张".to_string()]);
+    }
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("default"), "姓名长度不能小于2");
+    assert!(result.try_get_str("bytes").is_ok());
+    assert_eq!(result.get_error("graphemes"), "姓名长度不能小于2");
+    assert!(result.try_get_str("width").is_ok());
+}
+
+#[test]
+fn check_filter() {
+    assert_eq!(Trim.filter(" \t bob \n"), "bob");
+    assert_eq!(Lowercase.filter("BOB Ä"), "bob ä");
+    assert_eq!(CollapseWhitespace.filter("  bob \t\n  smith "), "bob smith");
+    assert_eq!(Nfc.filter("e\u{301}"), "\u{e9}");
+    assert_eq!(Nfkc.filter("①ﬁ"), "1fi");
+    assert_eq!(StripControl.filter("a\u{0}b\u{7f}\tc\r\n"), "ab\tc\r\n");
+    assert_eq!(HalfWidth.filter("１２３　ＡＢＣ！"), "123 ABC!");
+
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("email", "邮箱", Email)
+               .filter(Trim)
+               .filter(Lowercase))
+        .check(Checker::new("age", "年龄", I64)
+               .filter(HalfWidth)
+               .filter(Trim)
+               .meet(Rule::Min(18)))
+        .check(Checker::new("name", "姓名", Str)
+               .filter(CollapseWhitespace)
+               .meet(Rule::Max(9)))
+        .check(Checker::new("code", "编码", Str)
+               .set(CheckerOption::Multiple(true))
+               .filter(|value: &str| value.replace('-', ""))
+               .meet(Rule::Max(4)));
+
+    let mut params = HashMap::new();
+    params.insert("email".to_string(), vec![" Bob@Example.COM\n".to_string()]);
+    params.insert("age".to_string(), vec!["　２０ ".to_string()]);
+    params.insert("name".to_string(), vec!["  bob    smith ".to_string()]);
+    params.insert("code".to_string(), vec!["12-34".to_string(), "5-6".to_string()]);
+    let result = validator.evaluate(&params);
+    assert!(result.is_valid());
+    assert_eq!(result.try_get_str("email"), Ok("bob@example.com".to_string()));
+    assert_eq!(result.try_get_i64("age"), Ok(20));
+    assert_eq!(result.try_get_str("name"), Ok("bob smith".to_string()));
+    assert_eq!(result.get_required_multiple("code"),
+               vec![FieldValue::Str("1234".to_string()), FieldValue::Str("56".to_string())]);
+
+    params.insert("age".to_string(), vec!["１７".to_string()]);
+    params.insert("name".to_string(), vec!["bob  van  smith".to_string()]);
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("age"), "年龄不能小于18");
+    assert_eq!(result.get_error("name"), "姓名长度不能大于9");
+}
+
+#[test]
+fn check_default() {
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("page", "页码", I64)
+               .set(CheckerOption::Optional(true))
+               .set(CheckerOption::Default("1".to_string()))
+               .meet(Rule::Min(1)))
+        .check(Checker::new("sort", "排序", Choice::new(&["asc", "desc"]).ignore_case(true))
+               .set(CheckerOption::Default("DESC".to_string())))
+        .check(Checker::new("size", "每页", I64)
+               .set(CheckerOption::DefaultValue(FieldValue::I64(20)))
+               .meet(Rule::Max(100)))
+        .check(Group::new("filter", "筛选")
+               .set(CheckerOption::Optional(true))
+               .check(Checker::new("tag", "标签", Str)
+                      .set(CheckerOption::Multiple(true))
+                      .set(CheckerOption::Default("all".to_string()))));
+
+    let mut params = HashMap::new();
+    let result = validator.evaluate(&params);
+    assert!(result.is_valid());
+    assert_eq!(result.get_required("page").as_i64(), Some(1));
+    assert_eq!(result.try_get_str("sort"), Ok("desc".to_string()));
+    assert_eq!(result.try_get_i64("size"), Ok(20));
+    assert!(result.get_optional("filter").is_none());
+
+    params.insert("page".to_string(), vec!["3".to_string()]);
+    params.insert("size".to_string(), vec!["200".to_string()]);
+    params.insert("filter[q]".to_string(), vec!["x".to_string()]);
+    let result = validator.evaluate(&params);
+    assert_eq!(result.try_get_i64("page"), Ok(3));
+    assert_eq!(result.get_error("size"), "每页不能大于100");
+    assert_eq!(result.get_required_multiple("filter[tag]"), vec![FieldValue::Str("all".to_string())]);
+}
+
+#[test]
+#[should_panic(expected = "invalid default value: 页码不能小于1")]
+fn check_invalid_default() {
+    let mut validator = Validator::new();
+    validator.check(Checker::new("page", "页码", I64)
+                    .set(CheckerOption::Default("0".to_string()))
+                    .meet(Rule::Min(1)));
+}
+
+#[test]
+fn check_default_items() {
+    let mut validator = Validator::new();
+    assert!(validator.try_check(Checker::new("tag", "标签", Str)
+                                .set(CheckerOption::Multiple(true))
+                                .set(CheckerOption::Default("a".to_string()))
+                                .meet(Rule::MinItems(2))).is_err());
+    assert!(validator.try_check(Checker::new("page", "页码", I64)
+                                .set(CheckerOption::Default("0".to_string()))
+                                .meet(Rule::Min(1))).is_err());
+    assert!(validator.checkers.is_empty());
+
+    validator.try_check(Checker::new("tag", "标签", Str)
+                        .set(CheckerOption::Multiple(true))
+                        .set(CheckerOption::Default("a".to_string()))
+                        .meet(Rule::MaxItems(1))).ok().unwrap();
+    let result = validator.evaluate(&HashMap::new());
+    assert_eq!(result.get_required_multiple("tag"), vec![FieldValue::Str("a".to_string())]);
+}
+
+#[test]
+#[should_panic(expected = "invalid default value: 排序必须是asc、desc之一")]
+fn check_invalid_nested_default() {
+    let mut validator = Validator::new();
+    validator.check(Group::new("list", "列表")
+                    .check(Checker::new("sort", "排序", Choice::new(&["asc", "desc"]))
+                           .set(CheckerOption::Default("up".to_string()))));
+}
+
+#[test]
+#[should_panic(expected = "unsupported option for group `user`")]
+fn check_group_unsupported_option() {
+    Group::new("user", "用户")
+        .set(CheckerOption::CollectAll(true));
+}
+
+#[test]
+fn check_empty() {
+    let mut params = HashMap::new();
+    params.insert("name".to_string(), vec!["".to_string()]);
+    params.insert("nickname".to_string(), vec![" ".to_string()]);
+    params.insert("page".to_string(), vec!["".to_string()]);
+    params.insert("tag".to_string(), vec!["a".to_string(), "".to_string(), "b".to_string()]);
+    params.insert("memo".to_string(), vec!["".to_string()]);
+
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("name", "姓名", Str))
+        .check(Checker::new("nickname", "昵称", Str)
+               .set(CheckerOption::Optional(true)))
+        .check(Checker::new("page", "页码", I64)
+               .set(CheckerOption::Default("1".to_string())))
+        .check(Checker::new("tag", "标签", Str)
+               .set(CheckerOption::Multiple(true))
+               .meet(Rule::Min(1)))
+        .check(Checker::new("memo", "备注", Str)
+               .set(CheckerOption::Empty(EmptyPolicy::Value)));
+
+    let result = validator.evaluate(&params);
+    assert_eq!(result.try_get_str("name"), Ok("".to_string()));
+    assert_eq!(result.try_get_str("nickname"), Ok(" ".to_string()));
+    assert_eq!(result.get_error("page"), "页码格式不正确");
+    assert_eq!(result.get_field_errors("tag")[0].index, Some(1));
+
+    validator.empty_policy(EmptyPolicy::Missing);
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("name"), "姓名不能为空");
+    assert_eq!(result.get_optional("nickname"), None);
+    assert_eq!(result.try_get_i64("page"), Ok(1));
+    assert_eq!(result.get_required_multiple("tag"),
+               vec![FieldValue::Str("a".to_string()), FieldValue::Str("b".to_string())]);
+    assert_eq!(result.try_get_str("memo"), Ok("".to_string()));
+
+    params.insert("tag".to_string(), vec!["".to_string(), "x".to_string(), "".to_string(), "bb".to_string()]);
+    let mut validator = Validator::new();
+    validator
+        .empty_policy(EmptyPolicy::Reject)
+        .check(Checker::new("nickname", "昵称", Str)
+               .set(CheckerOption::Optional(true)))
+        .check(Checker::new("tag", "标签", Str)
+               .set(CheckerOption::Multiple(true))
+               .set(CheckerOption::Empty(EmptyPolicy::Missing))
+               .meet(Rule::Min(2)))
+        .check(Group::new("user", "用户")
+               .set(CheckerOption::Optional(true))
+               .check(Checker::new("city", "城市", Str)));
+    params.insert("user[city]".to_string(), vec!["".to_string()]);
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("nickname"), "昵称不能为空");
+    assert_eq!(result.get_field_errors("tag")[0].index, Some(1));
+    assert_eq!(result.get_error("tag"), "标签长度不能小于2");
+    assert_eq!(result.get_error("user[city]"), "城市不能为空");
+}
+
+#[test]
+fn check_cross_fields() {
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("password", "密码", Str)
+               .meet(Rule::Min(6)))
+        .check(Checker::new("confirm", "确认密码", Str)
+               .meet(Rule::EqualsField("password".to_string())))
+        .check(Checker::new("old_password", "旧密码", Str)
+               .set(CheckerOption::Optional(true))
+               .meet(Rule::NotEqualsField("password".to_string())))
+        .check(Checker::new("start", "开始日期", Date::new())
+               .meet(Rule::LessThanField("end".to_string())))
+        .check(Checker::new("end", "结束日期", Date::new()))
+        .check(Checker::new("min_price", "最低价", I64))
+        .check(Checker::new("max_price", "最高价", Decimal::new())
+               .meet(Rule::GreaterOrEqualField("min_price".to_string())))
+        .check(Group::new("items", "明细")
+               .set(CheckerOption::Multiple(true))
+               .check(Checker::new("qty", "数量", I64))
+               .check(Checker::new("shipped", "已发数量", I64)
+                      .meet(Rule::LessOrEqualField("qty".to_string())))
+               .check(Checker::new("ordered", "订购数量", I64)
+                      .set(CheckerOption::Optional(true))
+                      .meet(Rule::GreaterThanField("shipped".to_string()))));
+
+    let mut params = HashMap::new();
+    params.insert("password".to_string(), vec!["s3cret".to_string()]);
+    params.insert("confirm".to_string(), vec!["s3cret".to_string()]);
+    params.insert("old_password".to_string(), vec!["letmein".to_string()]);
+    params.insert("start".to_string(), vec!["2017-03-09".to_string()]);
+    params.insert("end".to_string(), vec!["2017-03-10".to_string()]);
+    params.insert("min_price".to_string(), vec!["100".to_string()]);
+    params.insert("max_price".to_string(), vec!["100.00".to_string()]);
+    params.insert("items[0][qty]".to_string(), vec!["3".to_string()]);
+    params.insert("items[0][shipped]".to_string(), vec!["3".to_string()]);
+    params.insert("items[1][qty]".to_string(), vec!["5".to_string()]);
+    params.insert("items[1][shipped]".to_string(), vec!["2".to_string()]);
+    params.insert("items[1][ordered]".to_string(), vec!["4".to_string()]);
+    let result = validator.evaluate(&params);
+    assert!(result.is_valid());
+    assert_eq!(result.try_get_str("confirm"), Ok("s3cret".to_string()));
+
+    params.insert("confirm".to_string(), vec!["secret".to_string()]);
+    params.insert("old_password".to_string(), vec!["s3cret".to_string()]);
+    params.insert("start".to_string(), vec!["2017-03-10".to_string()]);
+    params.insert("max_price".to_string(), vec!["99.99".to_string()]);
+    params.insert("items[0][shipped]".to_string(), vec!["4".to_string()]);
+    params.insert("items[1][ordered]".to_string(), vec!["2".to_string()]);
+    let result = validator.evaluate(&params);
+    assert!(!result.is_valid());
+    assert_eq!(result.get_error("confirm"), "确认密码必须与密码一致");
+    assert_eq!(result.try_get_str("confirm"), Err(GetError::Invalid("confirm".to_string())));
+    assert_eq!(result.get_error("old_password"), "旧密码不能与密码相同");
+    assert_eq!(result.get_error("start"), "开始日期必须小于结束日期");
+    assert_eq!(result.get_error("max_price"), "最高价不能小于最低价");
+    assert_eq!(result.get_error("items[0][shipped]"), "已发数量不能大于数量");
+    assert_eq!(result.get_error("items[1][ordered]"), "订购数量必须大于已发数量");
+    assert_eq!(result.try_get_i64("items[1][shipped]"), Ok(2));
+
+    // The other field is invalid, so the rule is skipped.
+    params.insert("password".to_string(), vec!["short".to_string()]);
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("password"), "密码长度不能小于6");
+    assert_eq!(result.try_get_str("confirm"), Ok("secret".to_string()));
+
+    // Values of kinds which can't be compared don't meet the rules ordering them.
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("start", "开始", Date::new())
+               .meet(Rule::LessThanField("end".to_string())))
+        .check(Checker::new("end", "结束", DateTime::new()))
+        .check(Checker::new("low", "下限", Str)
+               .meet(Rule::LessOrEqualField("high".to_string())))
+        .check(Checker::new("high", "上限", I64));
+    let mut mismatched = HashMap::new();
+    mismatched.insert("start".to_string(), vec!["2017-03-09".to_string()]);
+    mismatched.insert("end".to_string(), vec!["2017-03-10T00:00:00+08:00".to_string()]);
+    mismatched.insert("low".to_string(), vec!["1".to_string()]);
+    mismatched.insert("high".to_string(), vec!["2".to_string()]);
+    let result = validator.evaluate(&mismatched);
+    assert_eq!(result.get_error("start"), "开始必须小于结束");
+    assert_eq!(result.get_error("low"), "下限不能大于上限");
+
+    let mut validator = Validator::with_message(EnglishMessageRenderer);
+    validator
+        .check(Checker::new("password", "Password", Str))
+        .check(Checker::new("confirm", "Confirmation", Str)
+               .meet(Rule::EqualsField("password".to_string())));
+    validator.validate(&params);
+    assert_eq!(validator.get_error("confirm"), "Confirmation must match Password");
+}
+
+#[test]
+fn check_conditions() {
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("account_type", "账户类型", Str))
+        .check(Checker::new("company", "公司名称", Str)
+               .set(CheckerOption::RequiredIf("account_type".to_string(), "business".to_string())))
+        .check(Checker::new("id_number", "身份证号", Str)
+               .set(CheckerOption::RequiredUnless("account_type".to_string(), "business".to_string())))
+        .check(Checker::new("reason", "原因", Str)
+               .set(CheckerOption::Optional(true)))
+        .check(Checker::new("other_reason", "其他原因", Str)
+               .set(CheckerOption::RequiredIf("reason".to_string(), "other".to_string()))
+               .set(CheckerOption::ExcludedIf("reason".to_string(), "price".to_string())))
+        .check(Checker::new("phone", "电话", Str)
+               .set(CheckerOption::RequiredWithout("email".to_string())))
+        .check(Checker::new("email", "邮箱", Email)
+               .set(CheckerOption::Optional(true)))
+        .check(Group::new("address", "地址")
+               .set(CheckerOption::Optional(true))
+               .check(Checker::new("street", "街道", Str)
+                      .set(CheckerOption::RequiredWith("city".to_string())))
+               .check(Checker::new("city", "城市", Str)
+                      .set(CheckerOption::Optional(true))));
+
+    let mut params = HashMap::new();
+    params.insert("account_type".to_string(), vec!["business".to_string()]);
+    params.insert("company".to_string(), vec!["ACME".to_string()]);
+    params.insert("email".to_string(), vec!["bob@example.com".to_string()]);
+    params.insert("address[zip]".to_string(), vec!["200000".to_string()]);
+    let result = validator.evaluate(&params);
+    assert!(result.is_valid());
+    assert_eq!(result.get_optional("id_number"), None);
+    assert_eq!(result.get_optional("other_reason"), None);
+    assert_eq!(result.get_optional("phone"), None);
+
+    params.insert("account_type".to_string(), vec!["personal".to_string()]);
+    params.remove("company");
+    params.insert("reason".to_string(), vec!["other".to_string()]);
+    params.insert("email".to_string(), vec!["".to_string()]);
+    params.insert("address[city]".to_string(), vec!["上海".to_string()]);
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_optional("company"), None);
+    assert_eq!(result.get_error("id_number"), "身份证号不能为空");
+    assert_eq!(result.get_error("other_reason"), "其他原因不能为空");
+    assert_eq!(result.get_error("phone"), "电话不能为空");
+    assert_eq!(result.get_error("address[street]"), "街道不能为空");
+
+    params.insert("reason".to_string(), vec!["price".to_string()]);
+    params.insert("other_reason".to_string(), vec!["too high".to_string()]);
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("other_reason"), "其他原因必须为空");
+
+    params.insert("other_reason".to_string(), vec!["".to_string()]);
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_optional("other_reason"), None);
+
+    ////////////////////////////////////////////////
+
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("country", "国家", Str)
+               .filter(Trim)
+               .filter(Lowercase))
+        .check(Checker::new("id_number", "身份证号", Str)
+               .set(CheckerOption::RequiredIf("country".to_string(), "cn".to_string())))
+        .check(Checker::new("passport", "护照", Str)
+               .set(CheckerOption::RequiredWith("nickname".to_string())))
+        .check(Checker::new("nickname", "昵称", Str)
+               .set(CheckerOption::Optional(true))
+               .filter(Trim));
+
+    let mut params = HashMap::new();
+    params.insert("country".to_string(), vec![" CN ".to_string()]);
+    params.insert("nickname".to_string(), vec![" ".to_string()]);
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("id_number"), "身份证号不能为空");
+    assert_eq!(result.get_optional("passport"), None);
+}
+
+#[test]
+fn check_items() {
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("tag", "标签", Str)
+               .set(CheckerOption::Multiple(true))
+               .meet(Rule::Min(2))
+               .meet(Rule::MinItems(1))
+               .meet(Rule::MaxItems(3))
+               .meet(Rule::UniqueItems))
+        .check(Checker::new("step", "步骤", Decimal::new())
+               .set(CheckerOption::Multiple(true))
+               .set(CheckerOption::CollectAll(true))
+               .meet(Rule::UniqueItems)
+               .meet(Rule::SortedItems))
+        .check(Checker::new("name", "姓名", Str)
+               .meet(Rule::MinItems(2)));
+
+    let strings = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+    let mut params = HashMap::new();
+    params.insert("tag".to_string(), strings(&["red", "blue", "green"]));
+    params.insert("step".to_string(), strings(&["1", "1.5", "2"]));
+    params.insert("name".to_string(), strings(&["bob"]));
+    let result = validator.evaluate(&params);
+    assert!(result.is_valid());
+    assert_eq!(result.get_required_multiple("tag").len(), 3);
+
+    params.insert("tag".to_string(), strings(&["red", "blue", "green", "pink"]));
+    params.insert("step".to_string(), strings(&["1", "3", "2", "3.0"]));
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("tag"), "标签不能多于3项");
+    assert_eq!(result.get_field_errors("tag")[0].index, None);
+    let errors = result.get_field_errors("step");
+    assert_eq!(errors.iter().map(|e| e.text.as_str()).collect::<Vec<_>>(), vec!["步骤不能重复", "步骤必须按顺序排列"]);
+    assert_eq!(errors.iter().map(|e| e.index).collect::<Vec<_>>(), vec![Some(3), Some(2)]);
+
+    // Each value is checked before the items.
+    params.insert("tag".to_string(), strings(&["red", "b", "red", "pink"]));
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("tag"), "标签长度不能小于2");
+    assert_eq!(result.get_field_errors("tag")[0].index, Some(1));
+
+    params.insert("tag".to_string(), strings(&["red", "blue", "red"]));
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("tag"), "标签不能重复");
+
+    params.insert("tag".to_string(), Vec::new());
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("tag"), "标签不能少于1项");
+
+    let mut validator = Validator::with_message(EnglishMessageRenderer);
+    validator.check(Checker::new("tag", "tags", Str)
+                    .set(CheckerOption::Multiple(true))
+                    .meet(Rule::MaxItems(3)));
+    params.insert("tag".to_string(), strings(&["a", "b", "c", "d"]));
+    validator.validate(&params);
+    assert_eq!(validator.get_error("tag"), "pick at most 3 tags");
+}
+
+#[test]
+fn check_combinators() {
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("username", "用户名", Str)
+               .meet(Rule::Not(Box::new(Rule::Format(Format::from("(?i)admin")))))
+               .meet(Rule::NotEqualsField("password".to_string())))
+        .check(Checker::new("password", "密码", Str))
+        .check(Checker::new("contact", "联系方式", Str)
+               .meet(Rule::AnyOf(vec![Rule::Format(Format::full_match(r"1\d{10}").unwrap()),
+                                      Rule::AllOf(vec![Rule::Format(Format::from("@")),
+                                                       Rule::Max(20)])])))
+        .check(Checker::new("code", "编码", Str)
+               .set(CheckerOption::CollectAll(true))
+               .meet(Rule::AllOf(vec![Rule::Min(4),
+                                      Rule::Format(Format::full_match("[A-Z0-9]+").unwrap())]))
+               .meet(Rule::When(Box::new(|v| v.as_str().unwrap().starts_with('X')),
+                                Box::new(Rule::Max(5)))))
+        .check(Checker::new("age", "年龄", I64)
+               .meet(Rule::AnyOf(vec![Rule::Max(12), Rule::Min(60)])));
+
+    let mut params = HashMap::new();
+    params.insert("username".to_string(), vec!["bob".to_string()]);
+    params.insert("password".to_string(), vec!["secret".to_string()]);
+    params.insert("contact".to_string(), vec!["13800000000".to_string()]);
+    params.insert("code".to_string(), vec!["AB123".to_string()]);
+    params.insert("age".to_string(), vec!["65".to_string()]);
+    let result = validator.evaluate(&params);
+    assert!(result.is_valid());
+
+    params.insert("contact".to_string(), vec!["bob@example.com".to_string()]);
+    params.insert("code".to_string(), vec!["X1234".to_string()]);
+    params.insert("age".to_string(), vec!["8".to_string()]);
+    assert!(validator.evaluate(&params).is_valid());
+
+    params.insert("username".to_string(), vec!["SysAdmin".to_string()]);
+    params.insert("contact".to_string(), vec!["bob@a-very-long-domain.com".to_string()]);
+    params.insert("code".to_string(), vec!["x1".to_string()]);
+    params.insert("age".to_string(), vec!["30".to_string()]);
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("username"), "用户名不符合要求");
+    assert_eq!(result.get_error("contact"), "联系方式须满足以下条件之一：联系方式格式不正确；联系方式长度不能大于20");
+    assert_eq!(result.get_error("code"), "编码长度不能小于4；编码格式不正确");
+    assert_eq!(result.get_error("age"), "年龄须满足以下条件之一：年龄不能大于12；年龄不能小于60");
+
+    params.insert("code".to_string(), vec!["X12345".to_string()]);
+    params.insert("username".to_string(), vec!["secret".to_string()]);
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("code"), "编码长度不能大于5");
+    assert_eq!(result.get_error("username"), "用户名不能与密码相同");
+
+    let mut validator = Validator::with_message(EnglishMessageRenderer);
+    validator.check(Checker::new("age", "age", I64)
+                    .meet(Rule::AnyOf(vec![Rule::Max(12), Rule::Min(60)])));
+    validator.validate(&params);
+    assert_eq!(validator.get_error("age"), "age must meet one of: age can't be more than 12; age can't be less than 60");
+}
+
+#[test]
+#[should_panic(expected = "invalid rule for field `username`")]
+fn check_combined_cross_field() {
+    Checker::new("username", "用户名", Str)
+        .meet(Rule::Not(Box::new(Rule::EqualsField("password".to_string()))));
+}
+
+#[test]
+#[should_panic(expected = "invalid rule for field `tag`")]
+fn check_combined_items() {
+    Checker::new("tag", "标签", Str)
+        .set(CheckerOption::Multiple(true))
+        .meet(Rule::AnyOf(vec![Rule::MinItems(2), Rule::UniqueItems]));
+}
+
 #[test]
 fn check_lambda() {
     let mut validator = Validator::new();
@@ -225,6 +1219,71 @@ fn check_lambda() {
 
 }
 
+struct Luhn;
+
+impl CustomRule for Luhn {
+    fn check(&self, value: &FieldValue, context: &RuleContext) -> Result<(), Message> {
+        let digits = value.to_string();
+        let sum: u32 = digits.chars().rev().enumerate().map(|(i, c)| {
+            let d = c.to_digit(10).unwrap_or(0);
+            if i % 2 == 1 { if d * 2 > 9 { d * 2 - 9 } else { d * 2 } } else { d }
+        }).sum();
+        if sum % 10 != 0 {
+            return Err(context.message(MessageKind::Format, Vec::new()));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+#[allow(clippy::result_large_err)]
+fn check_custom_rule() {
+    let checkers = |validator: &mut Validator<EnglishMessageRenderer>| {
+        validator
+            .check(Checker::new("card", "card", Str)
+                   .meet(Rule::Custom(Box::new(Luhn))))
+            .check(Checker::new("name", "name", Str)
+                   .set(CheckerOption::Multiple(true))
+                   .meet(Rule::Custom(Box::new(|value: &FieldValue, context: &RuleContext| {
+                       if value.to_string().contains(' ') {
+                           return Err(context.message(MessageKind::NoneOf, vec![" ".to_string()]));
+                       }
+                       if context.raw == "root" {
+                           return Err(Message::any(&format!("{} {} is reserved", context.name, context.raw)));
+                       }
+                       Ok(())
+                   }))));
+    };
+    let mut validator = Validator::with_message(EnglishMessageRenderer);
+    checkers(&mut validator);
+
+    let mut params = HashMap::new();
+    params.insert("card".to_string(), vec!["4539578763621486".to_string()]);
+    params.insert("name".to_string(), vec!["bob".to_string(), "alice".to_string()]);
+    validator.validate(&params);
+    assert!(validator.is_valid());
+
+    validator.reset();
+    params.insert("card".to_string(), vec!["4539578763621487".to_string()]);
+    params.insert("name".to_string(), vec!["bob".to_string(), "bob smith".to_string()]);
+    validator.validate(&params);
+    assert_eq!(validator.get_error("card"), "card is in wrong format");
+    assert_eq!(validator.get_error("name"), "name can't be  ");
+    assert_eq!(validator.get_field_errors("name")[0].index, Some(1));
+
+    validator.reset();
+    params.insert("name".to_string(), vec!["root".to_string()]);
+    validator.validate(&params);
+    assert_eq!(validator.get_error("name"), "name root is reserved");
+
+    let mut validator = Validator::new();
+    validator.check(Checker::new("card", "卡号", Str)
+                    .meet(Rule::Not(Box::new(Rule::Custom(Box::new(Luhn))))));
+    params.insert("card".to_string(), vec!["4539578763621486".to_string()]);
+    validator.validate(&params);
+    assert_eq!(validator.get_error("card"), "卡号不符合要求");
+}
+
 #[test]
 fn check_title() {
     let mut validator = Validator::new();
@@ -348,3 +1407,354 @@ fn define_my_field_type() {
     assert!(validator.is_valid());
     assert_eq!(validator.get_required("test").as_str().unwrap(), "my:test_field".to_string());
 }
+
+#[test]
+fn evaluate_leaves_validator_untouched() {
+    let mut validator = Validator::new();
+    validator.check(Checker::new("username", "用户名", Str)
+                    .meet(Rule::Max(5))
+                    .meet(Rule::Min(2)));
+
+    let mut params = HashMap::new();
+    params.insert("username".to_string(), vec!["bob".to_string()]);
+    let result = validator.evaluate(&params);
+    assert!(result.is_valid());
+    assert_eq!(result.get_required("username").as_str().unwrap(), "bob".to_string());
+
+    ////////////////////////////////////////////////
+
+    let mut params = HashMap::new();
+    params.insert("username".to_string(), vec!["b".to_string()]);
+    let result = validator.evaluate(&params);
+    assert!(!result.is_valid());
+    assert_eq!(result.get_error("username"), "用户名长度不能小于2");
+    assert_eq!(result.get_errors(), vec!["用户名长度不能小于2"]);
+
+    assert!(validator.valid_data.is_empty());
+    assert!(validator.invalid_messages.is_empty());
+}
+
+struct Silent;
+impl Checkable for Silent {
+    fn check(&self, _params: &dyn Source) -> CheckResult {
+        Err(Vec::new())
+    }
+
+    fn get_name(&self) -> String {
+        "silent".to_string()
+    }
+}
+
+#[test]
+fn evaluate_failure_without_message() {
+    let mut validator = Validator::new();
+    validator.check(Silent);
+    let result = validator.evaluate(&HashMap::new());
+    assert!(!result.is_valid());
+    assert_eq!(result.get_error("silent"), "silent格式不正确");
+}
+
+#[test]
+fn share_validator_between_threads() {
+    let mut validator = Validator::new();
+    validator.check(Checker::new("age", "age", I64)
+                    .meet(Rule::Max(100))
+                    .meet(Rule::Lambda(Box::new(|v| v.as_i64().unwrap() % 2 == 0), None)));
+    let validator = Arc::new(validator);
+
+    let handles: Vec<_> = (0..4).map(|i| {
+        let validator = validator.clone();
+        thread::spawn(move || {
+            let mut params = HashMap::new();
+            params.insert("age".to_string(), vec![i.to_string()]);
+            validator.evaluate(&params).is_valid()
+        })
+    }).collect();
+
+    let valid: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(valid, vec![true, false, true, false]);
+}
+
+#[test]
+fn check_collect_all() {
+    let mut validator = Validator::new();
+    validator.check(Checker::new("password", "密码", Str)
+                    .set(CheckerOption::CollectAll(true))
+                    .meet(Rule::Min(8))
+                    .meet(Rule::Format(r"\d".into()))
+                    .meet(Rule::Format(r"[A-Z]".into())));
+
+    let mut params = HashMap::new();
+    params.insert("password".to_string(), vec!["abc".to_string()]);
+    validator.validate(&params);
+    assert!(!validator.is_valid());
+    assert_eq!(validator.get_error("password"), "密码长度不能小于8");
+    assert_eq!(validator.invalid_messages.get("password"), Some(&"密码长度不能小于8".to_string()));
+    assert_eq!(validator.get_field_errors("password"), vec![
+        InvalidMessage { index: None, text: "密码长度不能小于8".to_string() },
+        InvalidMessage { index: None, text: "密码格式不正确".to_string() },
+        InvalidMessage { index: None, text: "密码格式不正确".to_string() },
+    ]);
+
+    ////////////////////////////////////////////////
+
+    let mut validator = Validator::new();
+    validator.check(Checker::new("username", "用户名", Str)
+                    .set(CheckerOption::Multiple(true))
+                    .set(CheckerOption::CollectAll(true))
+                    .meet(Rule::Max(5))
+                    .meet(Rule::Min(2)));
+
+    let mut params = HashMap::new();
+    params.insert("username".to_string(), vec!["i".to_string(), "bob".to_string(), "hellokitty".to_string()]);
+    validator.validate(&params);
+    assert!(!validator.is_valid());
+    assert_eq!(validator.get_field_errors("username"), vec![
+        InvalidMessage { index: Some(0), text: "用户名长度不能小于2".to_string() },
+        InvalidMessage { index: Some(2), text: "用户名长度不能大于5".to_string() },
+    ]);
+
+    ////////////////////////////////////////////////
+
+    let mut validator = Validator::new();
+    validator.check(Checker::new("username", "用户名", Str)
+                    .set(CheckerOption::Multiple(true))
+                    .meet(Rule::Min(2)));
+    validator.validate(&params);
+    assert_eq!(validator.get_field_errors("username"), vec![
+        InvalidMessage { index: Some(0), text: "用户名长度不能小于2".to_string() },
+    ]);
+}
+
+#[test]
+fn try_getters() {
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("username", "用户名", Str)
+               .meet(Rule::Min(2)))
+        .check(Checker::new("age", "年龄", I64)
+               .set(CheckerOption::Optional(true)))
+        .check(Checker::new("email", "邮箱", Email));
+
+    let mut params = HashMap::new();
+    params.insert("username".to_string(), vec!["b".to_string()]);
+    params.insert("email".to_string(), vec!["abb@howadata.com".to_string()]);
+    let result = validator.evaluate(&params);
+
+    assert_eq!(result.try_get_required("username"), Err(GetError::Invalid("username".to_string())));
+    assert_eq!(result.try_get_required("age"), Err(GetError::Absent("age".to_string())));
+    assert_eq!(result.try_get_optional("age"), Ok(None));
+    assert_eq!(result.try_get_required("mobile"), Err(GetError::Unknown("mobile".to_string())));
+    assert_eq!(result.try_get_str("email"), Ok("abb@howadata.com".to_string()));
+    assert_eq!(result.try_get_i64("email"), Err(GetError::Mismatch("email".to_string())));
+    assert_eq!(result.try_get_error("username"), Ok("用户名长度不能小于2".to_string()));
+    assert_eq!(result.try_get_error("email"), Err(GetError::Valid("email".to_string())));
+    assert_eq!(result.try_get_error("mobile"), Err(GetError::Unknown("mobile".to_string())));
+    assert_eq!(result.try_get_some_error(), Some("用户名长度不能小于2".to_string()));
+    assert_eq!(GetError::Unknown("mobile".to_string()).to_string(), "field `mobile` is unknown");
+
+    ////////////////////////////////////////////////
+
+    params.insert("username".to_string(), vec!["bob".to_string()]);
+    params.insert("age".to_string(), vec!["20".to_string()]);
+    validator.validate(&params);
+    assert_eq!(validator.try_get_i64("age"), Ok(20));
+    assert_eq!(validator.try_get_optional_multiple("age").unwrap().unwrap().len(), 1);
+    assert_eq!(validator.try_get_some_error(), None);
+}
+
+#[test]
+fn validate_urlencoded() {
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("username", "用户名", Str)
+               .meet(Rule::Max(20)))
+        .check(Checker::new("tag", "标签", Str)
+               .set(CheckerOption::Multiple(true)))
+        .check(Checker::new("age", "年龄", I64)
+               .set(CheckerOption::Optional(true)));
+
+    let result = validator.validate_query("?username=bob%20lee%2B&tag=a+b&tag=%E7%BA%A2&flag&&x=%zz").unwrap();
+    assert!(result.is_valid());
+    assert_eq!(result.get_required("username").as_str().unwrap(), "bob lee+".to_string());
+    assert_eq!(result.get_required_multiple("tag").iter().map(|t| t.as_str().unwrap()).collect::<Vec<_>>(),
+               vec!["a b".to_string(), "红".to_string()]);
+    assert!(result.get_optional("age").is_none());
+
+    let result = validator.validate_urlencoded(b"username=bob&tag=a&age=old").unwrap();
+    assert_eq!(result.get_error("age"), "年龄格式不正确");
+
+    assert_eq!(validator.validate_urlencoded(b"username=%FF").err(), Some(ParseError::InvalidUtf8("username".to_string())));
+
+    ////////////////////////////////////////////////
+
+    validator.limits = Limits { max_pairs: 2, max_length: 20, ..Limits::default() };
+    assert_eq!(validator.validate_query("a=1&b=2&c=3").err(), Some(ParseError::TooManyPairs));
+    assert_eq!(validator.validate_query("username=bobbobbobbobbob").err(), Some(ParseError::TooLong));
+
+    validator.limits = Limits { max_key_length: 8, max_value_length: 4, max_values: 2, ..Limits::default() };
+    assert_eq!(validator.validate_query("username=bob&tag=a&tag=b").unwrap().get_required("username").as_str().unwrap(),
+               "bob".to_string());
+    assert_eq!(validator.validate_query("username1=bob").err(), Some(ParseError::KeyTooLong));
+    assert_eq!(validator.validate_query("username=%E7%BA%A2%E7%BA%A2").err(), Some(ParseError::ValueTooLong("username".to_string())));
+    assert_eq!(validator.validate_query("tag=a&tag=b&tag=c").err(), Some(ParseError::TooManyValues("tag".to_string())));
+}
+
+// A part of a multipart body: name, file name and content type, and data.
+type Part<'a> = (&'a str, Option<(&'a str, &'a str)>, &'a [u8]);
+
+fn multipart_body(parts: &[Part]) -> Vec<u8> {
+    let mut body = Vec::new();
+    for &(name, file, data) in parts {
+        body.extend_from_slice(b"--BOUNDARY\r\n");
+        match file {
+            Some((filename, content_type)) => {
+                body.extend_from_slice(format!("Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n", name, filename).as_bytes());
+                body.extend_from_slice(format!("Content-Type: {}\r\n\r\n", content_type).as_bytes());
+            },
+            None => {
+                body.extend_from_slice(format!("Content-Disposition: form-data; name=\"{}\"\r\n\r\n", name).as_bytes());
+            },
+        }
+        body.extend_from_slice(data);
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(b"--BOUNDARY--\r\n");
+    body
+}
+
+#[test]
+fn validate_multipart() {
+    let png = b"\x89PNG\r\n\x1a\n-rest-of-image";
+    let mut validator = Validator::new();
+    validator
+        .check(Checker::new("username", "用户名", Str)
+               .meet(Rule::Min(2)))
+        .check(Checker::new("avatar", "头像", File::new()
+                            .max_size(64)
+                            .content_types(&["image/png", "image/jpeg"])
+                            .extensions(&["png", "jpg"])
+                            .sniff(true)))
+        .check(Checker::new("resume", "简历", File::new())
+               .set(CheckerOption::Optional(true)));
+    let content_type = "multipart/form-data; boundary=\"BOUNDARY\"";
+
+    let body = multipart_body(&[
+        ("username", None, b"bob"),
+        ("avatar", Some(("me.PNG", "image/png")), png),
+        ("resume", Some(("", "application/octet-stream")), b""),
+    ]);
+    let result = validator.validate_multipart(&body, content_type).unwrap();
+    assert!(result.is_valid());
+    assert_eq!(result.try_get_str("username"), Ok("bob".to_string()));
+    assert_eq!(result.try_get_file("avatar"), Ok(UploadedFile {
+        filename: Some("me.PNG".to_string()),
+        content_type: Some("image/png".to_string()),
+        data: png.to_vec(),
+    }));
+    assert!(result.get_optional("resume").is_none());
+
+    ////////////////////////////////////////////////
+
+    let body = multipart_body(&[
+        ("username", None, b"bob"),
+        ("avatar", Some((r#"me; \"1\".png"#, "image/png")), png),
+    ]);
+    let result = validator.validate_multipart(&body, content_type).unwrap();
+    assert!(result.is_valid());
+    assert_eq!(result.try_get_file("avatar").unwrap().filename, Some(r#"me; "1".png"#.to_string()));
+
+    ////////////////////////////////////////////////
+
+    let cases: Vec<(Part, &str)> = vec![
+        (("avatar", Some(("me.png", "image/png")), &[b'x'; 65]), "头像长度不能大于64"),
+        (("avatar", Some(("me.gif", "image/png")), png), "头像格式不正确"),
+        (("avatar", Some(("me.png", "image/gif")), png), "头像格式不正确"),
+        (("avatar", Some(("me.png", "image/jpeg")), png), "头像格式不正确"),
+        (("avatar", Some(("me.png", "image/png")), b"not a png"), "头像格式不正确"),
+        (("avatar", None, b"me.png"), "头像格式不正确"),
+    ];
+    for (part, error) in cases {
+        let body = multipart_body(&[("username", None, b"bob"), part]);
+        let result = validator.validate_multipart(&body, content_type).unwrap();
+        assert_eq!(result.get_error("avatar"), error);
+    }
+
+    ////////////////////////////////////////////////
+
+    let body = multipart_body(&[("username", None, b"\xff")]);
+    assert_eq!(validator.validate_multipart(&body, content_type).err(), Some(ParseError::InvalidUtf8("username".to_string())));
+    assert!(validator.validate_multipart(b"--BOUNDARY\r\nbroken", content_type).is_err());
+    assert!(validator.validate_multipart(&body, "application/json").is_err());
+
+    validator.limits = Limits { max_value_length: 4, max_values: 1, ..Limits::default() };
+    let body = multipart_body(&[("username", None, b"bob"), ("avatar", Some(("me.png", "image/png")), png)]);
+    assert!(validator.validate_multipart(&body, content_type).unwrap().is_valid());
+    let body = multipart_body(&[("username", None, b"bobby")]);
+    assert_eq!(validator.validate_multipart(&body, content_type).err(), Some(ParseError::ValueTooLong("username".to_string())));
+    let body = multipart_body(&[("avatar", Some(("a.png", "image/png")), png), ("avatar", Some(("b.png", "image/png")), png)]);
+    assert_eq!(validator.validate_multipart(&body, content_type).err(), Some(ParseError::TooManyValues("avatar".to_string())));
+    validator.limits = Limits::default();
+
+    let mut form = Form::default();
+    form.fields.insert("username".to_string(), vec!["bob".to_string()]);
+    assert_eq!(validator.evaluate(&form).get_error("avatar"), "头像不能为空");
+}
+
+#[test]
+fn check_nested() {
+    assert_eq!(split_key("items[0][qty]"), vec!["items", "0", "qty"]);
+    assert_eq!(join_key(&["items", "0", "qty"]), "items[0][qty]");
+    assert_eq!(split_key("items]"), vec!["items]"]);
+
+    let mut validator = Validator::new();
+    validator
+        .check(Group::new("user", "用户")
+               .check(Checker::new("name", "姓名", Str))
+               .check(Group::new("address", "地址")
+                      .check(Checker::new("city", "城市", Str).meet(Rule::Max(4)))))
+        .check(Group::new("items", "明细")
+               .set(CheckerOption::Multiple(true))
+               .check(Checker::new("qty", "数量", I64).meet(Rule::Min(1)))
+               .check(Checker::new("tag", "标签", Str)
+                      .set(CheckerOption::Optional(true))
+                      .set(CheckerOption::Multiple(true))));
+
+    let mut params = HashMap::new();
+    params.insert("user[name]".to_string(), vec!["bob".to_string()]);
+    params.insert("user[address][city]".to_string(), vec!["上海".to_string()]);
+    params.insert("items[0][qty]".to_string(), vec!["1".to_string()]);
+    params.insert("items[0][tag][]".to_string(), vec!["a".to_string(), "b".to_string()]);
+    params.insert("items[2][qty]".to_string(), vec!["3".to_string()]);
+    let result = validator.evaluate(&params);
+    assert!(result.is_valid());
+    assert_eq!(result.try_get_str("user[name]"), Ok("bob".to_string()));
+    assert_eq!(result.try_get_str("user[address][city]"), Ok("上海".to_string()));
+    assert_eq!(result.try_get_i64("items[0][qty]"), Ok(1));
+    assert_eq!(result.try_get_i64("items[2][qty]"), Ok(3));
+    assert_eq!(result.get_required_multiple("items[0][tag]").len(), 2);
+    assert!(result.get_optional_multiple("items[2][tag]").is_none());
+    assert_eq!(result.get_required_multiple("items"),
+               vec![FieldValue::Str("0".to_string()), FieldValue::Str("2".to_string())]);
+
+    ////////////////////////////////////////////////
+
+    params.insert("user[address][city]".to_string(), vec!["乌鲁木齐市".to_string()]);
+    params.insert("items[2][qty]".to_string(), vec!["0".to_string()]);
+    let result = validator.evaluate(&params);
+    assert!(!result.is_valid());
+    assert_eq!(result.get_error("user[address][city]"), "城市长度不能大于4");
+    assert_eq!(result.get_error("items[2][qty]"), "数量不能小于1");
+    assert_eq!(result.try_get_i64("items[0][qty]"), Ok(1));
+
+    ////////////////////////////////////////////////
+
+    params.remove("user[name]");
+    params.remove("user[address][city]");
+    params.remove("items[0][qty]");
+    params.remove("items[0][tag][]");
+    params.remove("items[2][qty]");
+    let result = validator.evaluate(&params);
+    assert_eq!(result.get_error("user"), "用户不能为空");
+    assert_eq!(result.get_error("items"), "明细不能为空");
+}